@@ -7,7 +7,26 @@ pub const LAST_BID_PRICE: &str = "last_bid_price";
 pub const EDITION_DISTRIBUTOR: &str = "edition_distributor";
 pub const EDITION_ALLOWLIST: &str = "edition_allowlist";
 pub const EDITION_BUYER_INFO_ACCOUNT: &str = "edition_buyer_info_account";
+pub const SHARED_ESCROW: &str = "shared_escrow";
+pub const SEALED_BID_AUCTION: &str = "sealed_bid_auction";
+pub const SEALED_BID: &str = "sealed_bid";
+pub const SEALED_BID_ESCROW: &str = "sealed_bid_escrow";
+pub const CLAIMABLE_BALANCE: &str = "claimable_balance";
+pub const REVENUE_SPLIT: &str = "revenue_split";
+pub const FEE_OVERRIDE: &str = "fee_override";
+pub const TREASURY_SPLIT: &str = "treasury_split";
+pub const AUCTION_HOUSE_ROLES: &str = "auction_house_roles";
+pub const LAST_BID_PRICE_AUDIT_LOG: &str = "last_bid_price_audit_log";
 
 pub const MAX_NUMBER_OF_PRICE_PARAMS: usize = 6;
+pub const MAX_REVENUE_SPLIT_RECIPIENTS: usize = 10;
+
+// How long fee increases proposed with update_auction_house are pending before they can be
+// applied, which gives sellers notice before their listings settle at the new fees.
+pub const FEE_INCREASE_DELAY_IN_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+// How long treasury split recipients proposed with set_treasury_split are pending before
+// they can be applied, since distribute_treasury pays them permissionlessly.
+pub const TREASURY_SPLIT_DELAY_IN_SECONDS: i64 = 7 * 24 * 60 * 60;
 
 pub const BONK_MINT: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
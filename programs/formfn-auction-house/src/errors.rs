@@ -132,4 +132,142 @@ pub enum AuctionHouseError {
     InvalidAllowlistProof,
     #[msg("This address has already minted all of its allowlisted editions")]
     AllowlistAmountAlreadyMinted,
+    #[msg("All editions available from this edition distributor have already been minted")]
+    EditionSupplyCapReached,
+    #[msg("The max editions to sell cannot be less than the number of editions already minted")]
+    InvalidMaxEditionsToSell,
+    #[msg("The supply for this edition distributor has been locked")]
+    EditionSupplyLocked,
+    #[msg("The supply can only be locked after the sale has ended or sold out")]
+    CannotLockEditionSupplyDuringSale,
+    #[msg("A bot tax amount is required to set a bot tax recipient for non-native edition distributors")]
+    InvalidBotTaxConfig,
+    #[msg("The master edition token can only be withdrawn with close_edition_distributor_token_account")]
+    CannotSweepMasterEditionMint,
+    #[msg("The token account must be owned by the auction house fee account or treasury")]
+    InvalidSweepTokenAccountOwner,
+    #[msg("The LastBidPrice account cannot be closed while an auction is in progress")]
+    LastBidPriceAuctionInProgress,
+    #[msg("The LastBidPrice account cannot be closed because it is the only record of the primary sale")]
+    LastBidPriceRecordsPrimarySale,
+    #[msg("The LastBidPrice account cannot be closed while the NFT is listed")]
+    LastBidPriceNftIsListed,
+    #[msg("The trade state sale type is invalid")]
+    InvalidTradeStateSaleType,
+    #[msg("The trade state has expired")]
+    TradeStateExpired,
+    #[msg("The trade state expiry must be in the future")]
+    InvalidTradeStateExpiry,
+    #[msg("Only legacy trade states can be migrated")]
+    TradeStateNotLegacy,
+    #[msg("Shared escrows are only supported for auction houses with a native treasury mint")]
+    SharedEscrowRequiresNativeMint,
+    #[msg("Shared escrows can only be used to bid on auctions")]
+    SharedEscrowOnlyForAuctions,
+    #[msg("The shared escrow does not have enough unreserved funds")]
+    InsufficientSharedEscrowBalance,
+    #[msg("The shared escrow of the previous bidder must be provided")]
+    SharedEscrowNotProvided,
+    #[msg("The current bid is not backed by a shared escrow reservation")]
+    NoSharedEscrowReservation,
+    #[msg("The winning bid's shared escrow reservation must be settled before executing the sale")]
+    SharedEscrowReservationNotSettled,
+    #[msg("The current bid is backed by a shared escrow reservation which must be released first")]
+    LastBidPriceHasSharedEscrowReservation,
+    #[msg("The buy now price must be greater than 0 and the current highest bid")]
+    InvalidBuyNowPrice,
+    #[msg("Sealed bid auctions must have commit_end_time < reveal_end_time, both in the future")]
+    InvalidSealedBidAuctionTimes,
+    #[msg("Sealed bids must be placed with commit_sealed_bid")]
+    SealedBidAuctionRequiresCommit,
+    #[msg("The commit phase of this sealed bid auction has ended")]
+    SealedBidCommitPhaseEnded,
+    #[msg("Sealed bids can only be revealed during the reveal phase")]
+    SealedBidNotInRevealPhase,
+    #[msg("The revealed price and salt do not match the sealed bid commitment")]
+    InvalidSealedBidReveal,
+    #[msg("This sealed bid has already been revealed")]
+    SealedBidAlreadyRevealed,
+    #[msg("The revealed price exceeds the sealed bid deposit")]
+    SealedBidExceedsDeposit,
+    #[msg("The reveal phase of this sealed bid auction has not ended")]
+    SealedBidAuctionRevealNotEnded,
+    #[msg("This sealed bid auction has already been settled")]
+    SealedBidAuctionAlreadySettled,
+    #[msg("The buyer is not the winner of this sealed bid auction")]
+    SealedBidAuctionWinnerMismatch,
+    #[msg("The sealed bid auction account must be provided")]
+    SealedBidAuctionNotProvided,
+    #[msg("The winning sealed bid can only be refunded after settlement, or by the auction house")]
+    SealedBidWinnerNotSettled,
+    #[msg("The seller cannot bid on their own sealed bid auction")]
+    SellerCannotBid,
+    #[msg("All sealed bids must be refunded before the sealed bid auction can be closed")]
+    SealedBidsNotRefunded,
+    #[msg("The visible bid price cannot be greater than the max bid")]
+    InvalidMaxBid,
+    #[msg("Max bids can only be placed on auctions")]
+    MaxBidOnlyForAuctions,
+    #[msg("The tick size percent must be at most 100")]
+    InvalidTickSizePercent,
+    #[msg("Only one of the constant and percent tick sizes can be set")]
+    ConflictingTickSizes,
+    #[msg("The min tick size cannot be greater than the max tick size")]
+    InvalidTickSizeBounds,
+    #[msg("A claimable balance account was not provided in remaining accounts")]
+    ClaimableBalanceNotProvided,
+    #[msg("There is nothing to claim")]
+    NothingToClaim,
+    #[msg("A revenue split can have at most 10 recipients")]
+    TooManyRevenueSplitRecipients,
+    #[msg("Revenue split basis points must be positive and add up to 10,000")]
+    InvalidRevenueSplitBasisPoints,
+    #[msg("The revenue split account was not provided in remaining accounts")]
+    RevenueSplitNotProvided,
+    #[msg("A revenue split recipient account was not provided in remaining accounts")]
+    RevenueSplitRecipientNotProvided,
+    #[msg("The auction house has no treasury split")]
+    TreasurySplitNotSet,
+    #[msg("There is nothing to distribute")]
+    NothingToDistribute,
+    #[msg("The signer is not the pending authority")]
+    InvalidPendingAuthority,
+    #[msg("The account is not the pending withdrawal destination")]
+    InvalidPendingWithdrawalDestination,
+    #[msg("There are no pending fee changes")]
+    NoPendingFees,
+    #[msg("Pending fee changes cannot be applied before their effective time")]
+    PendingFeesNotEffective,
+    #[msg("The auction house is paused")]
+    AuctionHousePaused,
+    #[msg("The signer is not the auction house authority and does not have the required role")]
+    MissingAuctionHouseRole,
+    #[msg("The last bid price audit log was not provided in remaining accounts")]
+    LastBidPriceAuditLogNotProvided,
+    #[msg("The last bid price audit log does not have enough lamports to grow")]
+    LastBidPriceAuditLogNeedsFunding,
+    #[msg("The sealed bid and its escrow must be provided")]
+    SealedBidNotProvided,
+    #[msg("Every verified creator must sign to set a revenue split on a multi-creator mint")]
+    MissingCreatorSignature,
+    #[msg("There are no pending treasury split recipients")]
+    NoPendingTreasurySplit,
+    #[msg("Pending treasury split recipients cannot be applied before their effective time")]
+    PendingTreasurySplitNotEffective,
+    #[msg("Fee overrides cannot be higher than the auction house's fees")]
+    FeeOverrideExceedsAuctionHouseFees,
+    #[msg("Only the auction house authority can unpause")]
+    OnlyAuthorityCanUnpause,
+    #[msg("LastBidPrice accounts with an audit log, revenue split or tick size cannot be closed")]
+    LastBidPriceHasSettings,
+    #[msg("The treasury can only be paid out with distribute_treasury while it is split")]
+    TreasuryIsSplit,
+    #[msg("The account must be migrated with migrate_account first")]
+    AccountNeedsMigration,
+    #[msg("remaining_accounts must hold the accounts of each reserved edition recipient")]
+    InvalidReservedEditionAccounts,
+    #[msg("The trade state must be migrated with migrate_legacy_trade_state first")]
+    TradeStateNeedsMigration,
+    #[msg("Buy now is only supported for auctions")]
+    BuyNowOnlyForAuctions,
 }
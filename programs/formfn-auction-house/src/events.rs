@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{LastBidPriceOverrideKind, LastBidPriceSnapshot};
+
+/// Emitted whenever a suspected bot is taxed instead of being allowed to buy an edition.
+#[event]
+pub struct BotTaxCollectedEvent {
+    pub edition_distributor: Pubkey,
+    pub suspect: Pubkey,
+    pub recipient: Pubkey,
+    // The currency of the tax, i.e. the native mint if the tax was paid in lamports.
+    pub tax_mint: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+}
+
+/// Emitted whenever an admin instruction overrides the state of a LastBidPrice.
+#[event]
+pub struct LastBidPriceOverriddenEvent {
+    pub last_bid_price: Pubkey,
+    pub mint: Pubkey,
+    pub kind: LastBidPriceOverrideKind,
+    pub signer: Pubkey,
+    pub unix_timestamp: i64,
+    pub old: LastBidPriceSnapshot,
+    pub new: LastBidPriceSnapshot,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    new_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Completes an authority change proposed with update_auction_house, which must be signed by
+ * the new authority so the auction house can't be handed to an address nobody controls.
+ */
+pub fn handle_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let new_authority = &ctx.accounts.new_authority;
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if auction_house.pending_authority != Some(new_authority.key()) {
+        return Err(AuctionHouseError::InvalidPendingAuthority.into());
+    }
+
+    auction_house.authority = new_authority.key();
+    auction_house.pending_authority = None;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct AcceptFeeWithdrawalDestination<'info> {
+    fee_withdrawal_destination: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Completes a fee withdrawal destination change proposed with update_auction_house, which
+ * must be signed by the new destination.
+ */
+pub fn handle_accept_fee_withdrawal_destination(
+    ctx: Context<AcceptFeeWithdrawalDestination>,
+) -> Result<()> {
+    let fee_withdrawal_destination = &ctx.accounts.fee_withdrawal_destination;
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if auction_house.pending_fee_withdrawal_destination != Some(fee_withdrawal_destination.key())
+    {
+        return Err(AuctionHouseError::InvalidPendingWithdrawalDestination.into());
+    }
+
+    auction_house.fee_withdrawal_destination = fee_withdrawal_destination.key();
+    auction_house.pending_fee_withdrawal_destination = None;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct AcceptTreasuryWithdrawalDestination<'info> {
+    treasury_mint: Account<'info, Mint>,
+    /// CHECK: No need to deserialize.
+    treasury_withdrawal_destination: UncheckedAccount<'info>,
+    treasury_withdrawal_destination_owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = treasury_mint,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            treasury_mint.key().as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Completes a treasury withdrawal destination change proposed with update_auction_house,
+ * which must be signed by the owner of the new destination.
+ */
+pub fn handle_accept_treasury_withdrawal_destination(
+    ctx: Context<AcceptTreasuryWithdrawalDestination>,
+) -> Result<()> {
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let treasury_withdrawal_destination = &ctx.accounts.treasury_withdrawal_destination;
+    let treasury_withdrawal_destination_owner = &ctx.accounts.treasury_withdrawal_destination_owner;
+    let auction_house = &mut ctx.accounts.auction_house;
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if auction_house.pending_treasury_withdrawal_destination
+        != Some(treasury_withdrawal_destination.key())
+    {
+        return Err(AuctionHouseError::InvalidPendingWithdrawalDestination.into());
+    }
+
+    if !is_native {
+        assert_is_ata(
+            &treasury_withdrawal_destination.to_account_info(),
+            &treasury_withdrawal_destination_owner.key(),
+            &treasury_mint.key(),
+        )?;
+    } else {
+        assert_keys_equal(
+            treasury_withdrawal_destination.key(),
+            treasury_withdrawal_destination_owner.key(),
+        )?;
+    }
+
+    auction_house.treasury_withdrawal_destination = treasury_withdrawal_destination.key();
+    auction_house.pending_treasury_withdrawal_destination = None;
+
+    Ok(())
+}
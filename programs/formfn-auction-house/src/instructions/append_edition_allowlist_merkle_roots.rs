@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::*, AuctionHouse, AuctionHouseError, EditionAllowlistSettings, EditionDistributor,
-    EDITION_ALLOWLIST_SETTINGS_SIZE, NUMBER_OF_MERKLE_ROOTS_TO_STORE,
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole,
+    EditionAllowlistSettings, EditionDistributor, EDITION_ALLOWLIST_SETTINGS_SIZE,
+    EDITION_ALLOWLIST_SETTINGS_VERSION, NUMBER_OF_MERKLE_ROOTS_TO_STORE,
 };
 
 #[derive(Accounts)]
@@ -30,7 +31,6 @@ pub struct AppendEditionAllowlistMerkleRoots<'info> {
     )]
     edition_allowlist_settings: Account<'info, EditionAllowlistSettings>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -46,7 +46,16 @@ pub fn handle_append_edition_allowlist_merkle_roots<'info>(
     ctx: Context<'_, '_, '_, 'info, AppendEditionAllowlistMerkleRoots<'info>>,
     roots_to_append: Vec<[u8; 32]>,
 ) -> Result<()> {
+    assert_authority_or_role(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::DropManager,
+    )?;
+
     let edition_allowlist_settings = &mut ctx.accounts.edition_allowlist_settings;
+    assert_edition_allowlist_settings_migrated(&edition_allowlist_settings.to_account_info())?;
 
     let root_list = &mut edition_allowlist_settings.merkle_roots;
 
@@ -72,6 +81,7 @@ pub fn handle_append_edition_allowlist_merkle_roots<'info>(
 
     let bump = *ctx.bumps.get("edition_allowlist_settings").unwrap();
     edition_allowlist_settings.bump = bump;
+    edition_allowlist_settings.version = EDITION_ALLOWLIST_SETTINGS_VERSION;
 
     Ok(())
 }
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct ApplyPendingFees<'info> {
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Permissionlessly applies the fee increases proposed with update_auction_house once
+ * FEE_INCREASE_DELAY_IN_SECONDS has passed.
+ */
+pub fn handle_apply_pending_fees(ctx: Context<ApplyPendingFees>) -> Result<()> {
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if auction_house.pending_seller_fee_basis_points.is_none()
+        && auction_house.pending_seller_fee_basis_points_secondary.is_none()
+    {
+        return Err(AuctionHouseError::NoPendingFees.into());
+    }
+
+    assert_pending_fees_effective(
+        auction_house.pending_fees_effective_time,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    if let Some(sfbp) = auction_house.pending_seller_fee_basis_points.take() {
+        auction_house.seller_fee_basis_points = sfbp;
+    }
+    if let Some(sfbp) = auction_house.pending_seller_fee_basis_points_secondary.take() {
+        auction_house.seller_fee_basis_points_secondary = sfbp;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, TreasurySplit};
+
+#[derive(Accounts)]
+pub struct ApplyPendingTreasurySplit<'info> {
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY_SPLIT.as_bytes()
+        ],
+        bump = treasury_split.bump
+    )]
+    treasury_split: Account<'info, TreasurySplit>,
+}
+
+/**
+ * Permissionlessly applies the treasury split recipients proposed with set_treasury_split
+ * once TREASURY_SPLIT_DELAY_IN_SECONDS has passed, and records on the auction house whether
+ * the treasury is now split.
+ */
+pub fn handle_apply_pending_treasury_split(ctx: Context<ApplyPendingTreasurySplit>) -> Result<()> {
+    let auction_house = &mut ctx.accounts.auction_house;
+    let treasury_split = &mut ctx.accounts.treasury_split;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if treasury_split.pending_recipients.is_none() {
+        return Err(AuctionHouseError::NoPendingTreasurySplit.into());
+    }
+
+    if Clock::get()?.unix_timestamp < treasury_split.pending_recipients_effective_time {
+        return Err(AuctionHouseError::PendingTreasurySplitNotEffective.into());
+    }
+
+    treasury_split.recipients = treasury_split.pending_recipients.take().unwrap();
+    auction_house.has_treasury_split = !treasury_split.recipients.is_empty();
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{process_buy_edition, BuyEditionV2, BuyerMerkleAllowlistProofData};
+
+#[derive(Accounts)]
+pub struct BuyEditionForRecipient<'info> {
+    buy_edition: BuyEditionV2<'info>,
+    /// Wallet which receives the edition. The buyer_token_account and edition_buyer_info_account
+    /// in buy_edition must be derived from this wallet.
+    /// CHECK: No need to deserialize.
+    recipient: UncheckedAccount<'info>,
+}
+
+/**
+ * Same as buy_edition_v2, except the buyer only pays for the edition and the recipient
+ * receives it. This supports gift purchases and relayers which pay on behalf of users.
+ * The per-address limit and allowlist proofs apply to the recipient, not the buyer.
+ */
+pub fn handle_buy_edition_for_recipient<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyEditionForRecipient<'info>>,
+    edition_bump: u8,
+    // The price the buyer pays. Validated inside this instruction
+    price_in_lamports: u64,
+    recipient_edition_info_account_bump: u8,
+    recipient_merkle_allowlist_proof_data: Option<BuyerMerkleAllowlistProofData>,
+) -> Result<()> {
+    let recipient = ctx.accounts.recipient.to_account_info();
+    process_buy_edition(
+        &mut ctx.accounts.buy_edition,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        recipient,
+        None,
+        edition_bump,
+        price_in_lamports,
+        recipient_edition_info_account_bump,
+        recipient_merkle_allowlist_proof_data,
+    )
+}
@@ -6,13 +6,16 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{Mint, Token},
 };
-use mpl_token_metadata::state::{get_master_edition, TokenMetadataAccount};
+use mpl_token_metadata::{
+    instruction::{builders::PrintBuilder, InstructionBuilder, PrintArgs},
+    state::{get_master_edition, TokenMetadataAccount},
+};
 use spl_token::instruction::{initialize_mint, mint_to};
 
 use crate::{
-    constants::*, utils::*, AuctionHouse, AuctionHouseError, EditionAllowlistSettings,
-    EditionBuyerInfoAccount, EditionDistributor, PriceFunctionType,
-    EDITION_BUYER_INFO_ACCOUNT_SPACE,
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, BotTaxCollectedEvent,
+    EditionAllowlistSettings, EditionBuyerInfoAccount, EditionDistributor, PriceFunctionType,
+    EDITION_BUYER_INFO_ACCOUNT_SPACE, EDITION_BUYER_INFO_ACCOUNT_VERSION,
 };
 
 #[derive(Accounts)]
@@ -114,15 +117,9 @@ pub struct BuyEditionV2<'info> {
     #[account(mut)]
     seller_payment_receipt_token_account: UncheckedAccount<'info>,
     /// CHECK: This account is validated in the instruction handler. We don't deserialize
-    // it with Anchor here because it doesn't exist for every edition purchase.
-    #[account(
-        mut,
-        seeds = [
-            EDITION_BUYER_INFO_ACCOUNT.as_bytes(),
-            mint.key().as_ref(),
-            buyer.key().as_ref()],
-        bump
-    )]
+    // it with Anchor here because it doesn't exist for every edition purchase. The seeds
+    // are checked in the handler because they depend on the edition recipient.
+    #[account(mut)]
     edition_buyer_info_account: UncheckedAccount<'info>,
     /// CHECK: This account is validated in the instruction handler. We don't deserialize
     // it with Anchor here because it doesn't exist for every edition purchase.
@@ -153,40 +150,89 @@ pub fn handle_buy_edition_v2<'info>(
     buyer_edition_info_account_bump: u8,
     buyer_merkle_allowlist_proof_data: Option<BuyerMerkleAllowlistProofData>,
 ) -> Result<()> {
-    let owner = &ctx.accounts.owner;
-    let anti_bot_authority = &ctx.accounts.anti_bot_authority;
-    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
-    let auction_house = &ctx.accounts.auction_house;
-    let buyer = &ctx.accounts.buyer;
-    let edition_distributor = &ctx.accounts.edition_distributor;
-    let master_edition_pda = &ctx.accounts.master_edition_pda;
-    let system_program = &ctx.accounts.system_program;
-    let limited_edition_mint = &ctx.accounts.limited_edition_mint;
-    let master_edition_metadata = &ctx.accounts.master_edition_metadata;
-    let treasury_mint = &ctx.accounts.treasury_mint;
-    let authority = &ctx.accounts.authority;
-    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
-    let ata_program = &ctx.accounts.ata_program;
-    let token_program = &ctx.accounts.token_program;
-    let rent = &ctx.accounts.rent;
-    let buyer_token_account = &ctx.accounts.buyer_token_account;
+    let recipient = ctx.accounts.buyer.to_account_info();
+    process_buy_edition(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        recipient,
+        None,
+        edition_bump,
+        price_in_lamports,
+        buyer_edition_info_account_bump,
+        buyer_merkle_allowlist_proof_data,
+    )
+}
+
+/// Additional accounts needed to mint editions with Token Metadata's Print instruction.
+pub struct PrintEditionAccounts<'info> {
+    // Token record for the new edition. Only used for programmable editions, otherwise
+    // this should be the Token Metadata program id.
+    pub edition_token_record: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+}
+
+/**
+ * Shared logic for buying an edition. The buyer signs and pays for the edition, while the
+ * recipient receives it. The recipient is also the wallet used for the EditionBuyerInfoAccount
+ * (and therefore the per-address limit) and for allowlist proof verification.
+ *
+ * If print_edition_accounts is provided the edition is minted with Token Metadata's Print
+ * instruction, which supports programmable master editions. Otherwise the deprecated
+ * mint_new_edition_from_master_edition_via_token instruction is used.
+ */
+pub fn process_buy_edition<'info>(
+    accounts: &mut BuyEditionV2<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    recipient: AccountInfo<'info>,
+    print_edition_accounts: Option<PrintEditionAccounts<'info>>,
+    edition_bump: u8,
+    price_in_lamports: u64,
+    buyer_edition_info_account_bump: u8,
+    buyer_merkle_allowlist_proof_data: Option<BuyerMerkleAllowlistProofData>,
+) -> Result<()> {
+    let owner = &accounts.owner;
+    let anti_bot_authority = &accounts.anti_bot_authority;
+    let auction_house_treasury = &accounts.auction_house_treasury;
+    let auction_house = &accounts.auction_house;
+    let buyer = &accounts.buyer;
+    let edition_distributor = &accounts.edition_distributor;
+    let master_edition_pda = &accounts.master_edition_pda;
+    let system_program = &accounts.system_program;
+    let limited_edition_mint = &accounts.limited_edition_mint;
+    let master_edition_metadata = &accounts.master_edition_metadata;
+    let treasury_mint = &accounts.treasury_mint;
+    let authority = &accounts.authority;
+    let auction_house_fee_account = &accounts.auction_house_fee_account;
+    let ata_program = &accounts.ata_program;
+    let token_program = &accounts.token_program;
+    let rent = &accounts.rent;
+    let buyer_token_account = &accounts.buyer_token_account;
     // TODO(@bryancho): add validation to check that the mint equals treasury mint key
-    let buyer_payment_token_account = &ctx.accounts.buyer_payment_token_account;
-    let seller_payment_receipt_token_account = &ctx.accounts.seller_payment_receipt_token_account;
-    let mint = &ctx.accounts.mint;
-    let edition_buyer_info_account = &ctx.accounts.edition_buyer_info_account;
-    let edition_allowlist_settings = &ctx.accounts.edition_allowlist_settings;
+    let buyer_payment_token_account = &accounts.buyer_payment_token_account;
+    let seller_payment_receipt_token_account = &accounts.seller_payment_receipt_token_account;
+    let mint = &accounts.mint;
+    let edition_buyer_info_account = &accounts.edition_buyer_info_account;
+    let edition_allowlist_settings = &accounts.edition_allowlist_settings;
 
-    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_valid_auction_house(program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
 
     let is_anti_bot_authority_valid =
-        assert_valid_anti_bot_authority(ctx.program_id, &anti_bot_authority.key());
+        assert_valid_anti_bot_authority(program_id, &anti_bot_authority.key());
 
     if let Err(error) = is_anti_bot_authority_valid {
-        punish_bots(
+        collect_bot_tax(
             error,
+            edition_distributor,
+            auction_house,
             buyer.to_account_info(),
+            buyer_payment_token_account.to_account_info(),
             auction_house_treasury.to_account_info(),
+            auction_house_fee_account.to_account_info(),
+            remaining_accounts,
+            token_program.to_account_info(),
             system_program.to_account_info(),
         )?;
 
@@ -195,10 +241,16 @@ pub fn handle_buy_edition_v2<'info>(
 
     if edition_distributor.anti_bot_protection_enabled && !anti_bot_authority.is_signer {
         let error_code = AuctionHouseError::InvalidAntiBotAuthority;
-        punish_bots(
+        collect_bot_tax(
             error_code.into(),
+            edition_distributor,
+            auction_house,
             buyer.to_account_info(),
+            buyer_payment_token_account.to_account_info(),
             auction_house_treasury.to_account_info(),
+            auction_house_fee_account.to_account_info(),
+            remaining_accounts,
+            token_program.to_account_info(),
             system_program.to_account_info(),
         )?;
 
@@ -241,6 +293,16 @@ pub fn handle_buy_edition_v2<'info>(
         clock.unix_timestamp,
     );
 
+    assert_derivation(
+        program_id,
+        &edition_buyer_info_account.to_account_info(),
+        &[
+            EDITION_BUYER_INFO_ACCOUNT.as_bytes(),
+            mint.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+    )?;
+
     let limit_per_address = edition_distributor.limit_per_address;
 
     // Create the EditionBuyerInfoAccount first if the edition has a limit_per_address
@@ -250,12 +312,12 @@ pub fn handle_buy_edition_v2<'info>(
             let signer_seeds = [
                 EDITION_BUYER_INFO_ACCOUNT.as_bytes().as_ref(),
                 &mint.key().to_bytes(),
-                &buyer.key().to_bytes(),
+                &recipient.key().to_bytes(),
                 &[buyer_edition_info_account_bump],
             ];
 
             create_or_allocate_account_raw(
-                *ctx.program_id,
+                *program_id,
                 &edition_buyer_info_account,
                 &rent.to_account_info(),
                 &system_program,
@@ -265,10 +327,13 @@ pub fn handle_buy_edition_v2<'info>(
                 &signer_seeds,
             )?;
 
-            write_anchor_account_discriminator(
-                &edition_buyer_info_account,
-                &<EditionBuyerInfoAccount as anchor_lang::Discriminator>::discriminator(),
-            )?;
+            let new_edition_buyer_info_account = EditionBuyerInfoAccount {
+                version: EDITION_BUYER_INFO_ACCOUNT_VERSION,
+                ..Default::default()
+            };
+            // This writes the discriminator along with the account data.
+            new_edition_buyer_info_account
+                .try_serialize(&mut &mut edition_buyer_info_account.try_borrow_mut_data()?[..])?;
         }
     }
 
@@ -283,6 +348,7 @@ pub fn handle_buy_edition_v2<'info>(
         let proof = proof_data.proof;
         let root_index_for_proof = proof_data.root_index_for_proof as usize;
 
+        assert_edition_allowlist_settings_migrated(edition_allowlist_settings)?;
         let edition_allowlist_settings_account: Account<EditionAllowlistSettings> =
             Account::try_from(&edition_allowlist_settings)?;
 
@@ -301,7 +367,7 @@ pub fn handle_buy_edition_v2<'info>(
 
         let leaf = anchor_lang::solana_program::keccak::hashv(&[
             &[0x00],
-            &buyer.key().to_bytes(),
+            &recipient.key().to_bytes(),
             &mint.key().to_bytes(),
             &amount.to_le_bytes(),
         ]);
@@ -336,8 +402,8 @@ pub fn handle_buy_edition_v2<'info>(
         edition_buyer_info_account.exit(&crate::ID)?;
 
         msg!(
-            "Valid merkle allowlist proof submitted by {} with root index {}.",
-            buyer.key(),
+            "Valid merkle allowlist proof submitted for {} with root index {}.",
+            recipient.key(),
             root_index_for_proof
         );
     }
@@ -364,42 +430,85 @@ pub fn handle_buy_edition_v2<'info>(
 
     let is_native = treasury_mint.key() == spl_token::native_mint::id();
 
-    create_ata_for_buy_edition(
-        buyer_token_account.to_account_info(),
-        buyer.to_account_info(),
-        limited_edition_mint.to_account_info(),
-        ata_program.to_account_info(),
-        token_program.to_account_info(),
-        system_program.to_account_info(),
-        rent.to_account_info(),
-        &rent,
-    )?;
+    // The Print instruction creates the new mint and token account itself.
+    if print_edition_accounts.is_none() {
+        create_ata_for_buy_edition(
+            buyer_token_account.to_account_info(),
+            buyer.to_account_info(),
+            recipient.to_account_info(),
+            limited_edition_mint.to_account_info(),
+            ata_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+            &rent,
+        )?;
+    }
 
     // We will mint the next available edition
     let master_edition = get_master_edition(master_edition_pda)?;
     let next_available_edition = (*master_edition).supply().checked_add(1).unwrap();
-
-    mint_next_edition(
-        ctx.accounts.token_metadata_program.to_account_info(),
-        ctx.accounts.limited_edition_metadata.to_account_info(),
-        ctx.accounts.limited_edition_pda.to_account_info(),
-        ctx.accounts.master_edition_pda.to_account_info(),
-        ctx.accounts.limited_edition_mint.to_account_info(),
-        ctx.accounts.edition_marker_pda.to_account_info(),
-        ctx.accounts.buyer.to_account_info(),
-        ctx.accounts.edition_distributor.to_account_info(),
-        ctx.accounts.master_edition_token_account.to_account_info(),
-        ctx.accounts.owner.to_account_info(),
-        ctx.accounts.master_edition_metadata.to_account_info(),
-        ctx.accounts.mint.to_account_info(),
-        ctx.accounts.rent.to_account_info(),
-        &edition_distributor.master_edition_mint,
-        edition_distributor.bump,
+    assert_edition_supply_available(
+        edition_distributor.max_editions_to_sell,
+        edition_distributor.is_supply_locked,
         next_available_edition,
     )?;
 
+    match print_edition_accounts {
+        Some(print_edition_accounts) => {
+            print_next_edition(
+                accounts.token_metadata_program.to_account_info(),
+                accounts.limited_edition_metadata.to_account_info(),
+                accounts.limited_edition_pda.to_account_info(),
+                accounts.limited_edition_mint.to_account_info(),
+                recipient.to_account_info(),
+                accounts.buyer_token_account.to_account_info(),
+                print_edition_accounts.edition_token_record,
+                accounts.master_edition_pda.to_account_info(),
+                accounts.edition_marker_pda.to_account_info(),
+                accounts.buyer.to_account_info(),
+                accounts.edition_distributor.to_account_info(),
+                accounts.master_edition_token_account.to_account_info(),
+                accounts.master_edition_metadata.to_account_info(),
+                accounts.owner.to_account_info(),
+                accounts.token_program.to_account_info(),
+                accounts.ata_program.to_account_info(),
+                print_edition_accounts.sysvar_instructions,
+                accounts.system_program.to_account_info(),
+                &edition_distributor.master_edition_mint,
+                edition_distributor.bump,
+                next_available_edition,
+            )?;
+        }
+        None => {
+            mint_next_edition(
+                accounts.token_metadata_program.to_account_info(),
+                accounts.limited_edition_metadata.to_account_info(),
+                accounts.limited_edition_pda.to_account_info(),
+                accounts.master_edition_pda.to_account_info(),
+                accounts.limited_edition_mint.to_account_info(),
+                accounts.edition_marker_pda.to_account_info(),
+                accounts.buyer.to_account_info(),
+                accounts.edition_distributor.to_account_info(),
+                accounts.master_edition_token_account.to_account_info(),
+                accounts.owner.to_account_info(),
+                accounts.master_edition_metadata.to_account_info(),
+                accounts.mint.to_account_info(),
+                accounts.rent.to_account_info(),
+                &edition_distributor.master_edition_mint,
+                edition_distributor.bump,
+                next_available_edition,
+            )?;
+        }
+    }
+
+    // Reserved editions are minted outside of the sale, so they are excluded from the
+    // edition number used for pricing.
+    let edition_number_for_pricing = next_available_edition
+        .checked_sub(edition_distributor.reserved_number_minted)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
     let price_for_edition = get_price_for_edition(
-        next_available_edition,
+        edition_number_for_pricing,
         &edition_distributor.price_function,
         is_allowlist_sale,
         edition_distributor.allowlist_sale_price,
@@ -426,8 +535,22 @@ pub fn handle_buy_edition_v2<'info>(
         }
     }
 
-    // Take platform fee
-    let total_fee = (auction_house.seller_fee_basis_points as u128)
+    let metadata = mpl_token_metadata::state::Metadata::from_account_info(
+        &accounts.master_edition_metadata,
+    )?;
+
+    // Take platform fee, which may be overridden for the distributor or its creators
+    let fee_override = get_fee_override_from_remaining_accounts(
+        remaining_accounts,
+        program_id,
+        &auction_house.key(),
+        &edition_distributor.master_edition_mint,
+        &metadata,
+        clock.unix_timestamp,
+    )?;
+    let seller_fee_basis_points =
+        get_seller_fee_basis_points(auction_house, fee_override.as_ref(), false);
+    let total_fee = (seller_fee_basis_points as u128)
         .checked_mul(price_in_lamports as u128)
         .ok_or(AuctionHouseError::NumericalOverflow)?
         .checked_div(10000)
@@ -467,9 +590,6 @@ pub fn handle_buy_edition_v2<'info>(
         .checked_sub(total_fee)
         .ok_or(AuctionHouseError::NumericalOverflow)?;
 
-    let metadata = mpl_token_metadata::state::Metadata::from_account_info(
-        &ctx.accounts.master_edition_metadata,
-    )?;
     let should_split_primary =
         should_split_primary_sale(&metadata, &owner.key(), get_has_been_sold(&metadata, None));
 
@@ -488,7 +608,42 @@ pub fn handle_buy_edition_v2<'info>(
         &auction_house_fee_payer_seeds,
     )?;
 
-    if should_split_primary {
+    if edition_distributor.has_revenue_split {
+        let revenue_split = get_revenue_split_from_remaining_accounts(
+            remaining_accounts,
+            program_id,
+            &auction_house_key,
+            &edition_distributor.master_edition_mint,
+        )?;
+        let auction_house_seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            &[auction_house.bump],
+        ];
+        // The buyer pays the recipients directly, from their token account if non-native.
+        let payer_account = if is_native {
+            buyer.to_account_info()
+        } else {
+            buyer_payment_token_account.to_account_info()
+        };
+        pay_revenue_split(
+            remaining_accounts,
+            &revenue_split,
+            &payer_account,
+            buyer,
+            &fee_payer,
+            &treasury_mint.to_account_info(),
+            &ata_program,
+            &token_program,
+            &system_program,
+            &rent.to_account_info(),
+            &auction_house_seeds,
+            &fee_payer_seeds,
+            buyer_leftover_after_fees,
+            is_native,
+        )?;
+    } else if should_split_primary {
         if !is_native {
             let auction_house_seeds = [
                 PREFIX.as_bytes(),
@@ -497,7 +652,7 @@ pub fn handle_buy_edition_v2<'info>(
                 &[auction_house.bump],
             ];
             split_primary_sale_between_creators_non_native(
-                &mut ctx.remaining_accounts.iter(),
+                &mut remaining_accounts.iter(),
                 &master_edition_metadata,
                 &buyer_payment_token_account,
                 buyer,
@@ -518,7 +673,7 @@ pub fn handle_buy_edition_v2<'info>(
                 &[edition_distributor.bump],
             ];
             split_primary_sale_between_creators_native(
-                &mut ctx.remaining_accounts.iter(),
+                &mut remaining_accounts.iter(),
                 &master_edition_metadata,
                 buyer,
                 system_program,
@@ -565,9 +720,8 @@ pub fn handle_buy_edition_v2<'info>(
     let master_edition_mint = edition_distributor.master_edition_mint.key();
 
     // This happens down here to avoid mutable borrow issues with the edition_distributor account.
+    let edition_distributor = &mut accounts.edition_distributor;
     if is_allowlist_sale {
-        let edition_distributor = &mut ctx.accounts.edition_distributor;
-
         // Increment the number sold for the allowlist.
         edition_distributor.allowlist_number_sold = edition_distributor
             .allowlist_number_sold
@@ -575,6 +729,23 @@ pub fn handle_buy_edition_v2<'info>(
             .unwrap();
     }
 
+    edition_distributor.total_number_sold = edition_distributor
+        .total_number_sold
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    edition_distributor.total_gross_revenue = edition_distributor
+        .total_gross_revenue
+        .checked_add(price_in_lamports)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    edition_distributor.total_platform_fees = edition_distributor
+        .total_platform_fees
+        .checked_add(total_fee)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    edition_distributor.total_creator_proceeds = edition_distributor
+        .total_creator_proceeds
+        .checked_add(buyer_leftover_after_fees)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
     // NOTE: be careful when changing this log line! AuctionHouseSdk relies on it (see getEditionNumberFromTx).
     msg!(
         "Bought edition #{} for mint {}",
@@ -605,7 +776,10 @@ pub fn handle_buy_edition_v2<'info>(
 #[inline(never)]
 pub fn create_ata_for_buy_edition<'a>(
     ata: AccountInfo<'a>,
-    buyer: AccountInfo<'a>,
+    // Pays for the new accounts and is the initial mint authority of the new mint.
+    payer: AccountInfo<'a>,
+    // Owner of the new ATA, i.e. the wallet which receives the edition.
+    ata_owner: AccountInfo<'a>,
     limited_edition_mint: AccountInfo<'a>,
     ata_program: AccountInfo<'a>,
     token_program: AccountInfo<'a>,
@@ -616,14 +790,14 @@ pub fn create_ata_for_buy_edition<'a>(
     let min_rent_lamports = rent_struct.minimum_balance(Mint::LEN).max(1);
     invoke_signed(
         &system_instruction::create_account(
-            &buyer.key(),
+            &payer.key(),
             &limited_edition_mint.key(),
             min_rent_lamports,
             Mint::LEN as u64,
             &token_program.key(),
         ),
         &[
-            buyer.to_account_info(),
+            payer.to_account_info(),
             limited_edition_mint.to_account_info(),
             system_program.to_account_info(),
         ],
@@ -634,8 +808,8 @@ pub fn create_ata_for_buy_edition<'a>(
         &initialize_mint(
             &token_program.key(),
             &limited_edition_mint.key(),
-            &buyer.key(),
-            Some(&buyer.key()),
+            &payer.key(),
+            Some(&payer.key()),
             0,
         )
         .unwrap(),
@@ -649,9 +823,9 @@ pub fn create_ata_for_buy_edition<'a>(
 
     make_ata(
         ata.to_account_info(),
-        buyer.to_account_info(),
+        ata_owner.to_account_info(),
         limited_edition_mint.to_account_info(),
-        buyer.to_account_info(),
+        payer.to_account_info(),
         ata_program.to_account_info(),
         token_program.to_account_info(),
         system_program.to_account_info(),
@@ -664,7 +838,7 @@ pub fn create_ata_for_buy_edition<'a>(
             &token_program.key(),
             &limited_edition_mint.key(),
             &ata.key(),
-            &buyer.key(),
+            &payer.key(),
             &[],
             1,
         )
@@ -672,7 +846,7 @@ pub fn create_ata_for_buy_edition<'a>(
         &[
             limited_edition_mint.to_account_info(),
             ata.to_account_info(),
-            buyer.to_account_info(),
+            payer.to_account_info(),
             token_program.to_account_info(),
         ],
         &[],
@@ -745,3 +919,200 @@ pub fn mint_next_edition<'a>(
 
     Ok(())
 }
+
+#[inline(never)]
+pub fn print_next_edition<'a>(
+    token_metadata_program: AccountInfo<'a>,
+    edition_metadata: AccountInfo<'a>,
+    edition: AccountInfo<'a>,
+    edition_mint: AccountInfo<'a>,
+    edition_token_account_owner: AccountInfo<'a>,
+    edition_token_account: AccountInfo<'a>,
+    edition_token_record: AccountInfo<'a>,
+    master_edition: AccountInfo<'a>,
+    edition_marker_pda: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    edition_distributor: AccountInfo<'a>,
+    master_token_account: AccountInfo<'a>,
+    master_metadata: AccountInfo<'a>,
+    update_authority: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    ata_program: AccountInfo<'a>,
+    sysvar_instructions: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    edition_distributor_master_edition_mint: &Pubkey,
+    edition_distributor_bump: u8,
+    next_available_edition: u64,
+) -> Result<()> {
+    let mut print_builder = PrintBuilder::new();
+    print_builder
+        .edition_metadata(*edition_metadata.key)
+        .edition(*edition.key)
+        .edition_mint(*edition_mint.key)
+        .edition_token_account_owner(*edition_token_account_owner.key)
+        .edition_token_account(*edition_token_account.key)
+        .edition_mint_authority(*payer.key)
+        .master_edition(*master_edition.key)
+        .edition_marker_pda(*edition_marker_pda.key)
+        .payer(*payer.key)
+        .master_token_account_owner(*edition_distributor.key)
+        .master_token_account(*master_token_account.key)
+        .master_metadata(*master_metadata.key)
+        .update_authority(*update_authority.key)
+        .spl_token_program(*token_program.key)
+        .spl_ata_program(*ata_program.key)
+        .sysvar_instructions(*sysvar_instructions.key)
+        .system_program(*system_program.key)
+        .initialize_mint(true);
+
+    // The token record is optional, in which case the Token Metadata program id is passed instead.
+    if *edition_token_record.key != mpl_token_metadata::id() {
+        print_builder.edition_token_record(*edition_token_record.key);
+    }
+
+    let instruction = print_builder
+        .build(PrintArgs::V1 {
+            edition: next_available_edition,
+        })
+        .map_err(|_| ProgramError::InvalidArgument)?
+        .instruction();
+
+    let account_infos = [
+        token_metadata_program,
+        edition_metadata,
+        edition,
+        edition_mint,
+        edition_token_account_owner,
+        edition_token_account,
+        edition_token_record,
+        master_edition,
+        edition_marker_pda,
+        payer,
+        edition_distributor,
+        master_token_account,
+        master_metadata,
+        update_authority,
+        token_program,
+        ata_program,
+        sysvar_instructions,
+        system_program,
+    ];
+
+    let edition_distributor_seeds = [
+        EDITION_DISTRIBUTOR.as_bytes(),
+        &edition_distributor_master_edition_mint.to_bytes(),
+        &[edition_distributor_bump],
+    ];
+
+    invoke_signed(&instruction, &account_infos, &[&edition_distributor_seeds])?;
+
+    Ok(())
+}
+
+/**
+ * Charges the bot tax without ever failing because of accounts the suspect controls. The tax
+ * is paid in the distributor's treasury mint if it is an SPL token with a bot_tax_amount,
+ * and falls back to a lamport tax if the suspect's token account or every recipient token
+ * account is invalid.
+ *
+ * Lamports go to the configured recipient (native distributors only) or the treasury if it
+ * holds lamports, and otherwise to the auction house fee account, since lamports sent to a
+ * token account could not be withdrawn.
+ */
+#[inline(never)]
+#[allow(clippy::too_many_arguments)]
+pub fn collect_bot_tax<'a>(
+    error: Error,
+    edition_distributor: &Account<'a, EditionDistributor>,
+    auction_house: &AuctionHouse,
+    buyer: AccountInfo<'a>,
+    buyer_payment_token_account: AccountInfo<'a>,
+    auction_house_treasury: AccountInfo<'a>,
+    auction_house_fee_account: AccountInfo<'a>,
+    remaining_accounts: &[AccountInfo<'a>],
+    token_program: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+) -> Result<()> {
+    // Legacy distributors have a zeroed treasury_mint and only support SOL.
+    let is_native = edition_distributor.treasury_mint == spl_token::native_mint::id()
+        || edition_distributor.treasury_mint == ZERO_PUBKEY;
+
+    // bot_tax_amount is in the treasury mint for SPL distributors.
+    let spl_bot_tax_amount = edition_distributor.bot_tax_amount.filter(|_| !is_native);
+    if let Some(bot_tax_amount) = spl_bot_tax_amount {
+        let tax_mint = edition_distributor.treasury_mint;
+        let buyer_token_account =
+            get_bot_tax_token_account(&buyer_payment_token_account, &tax_mint, Some(buyer.key));
+        let is_valid_recipient = |recipient: &AccountInfo<'a>| {
+            get_bot_tax_token_account(recipient, &tax_mint, None).is_some()
+        };
+        let recipient = Some(get_bot_tax_recipient(
+            edition_distributor.bot_tax_recipient,
+            remaining_accounts,
+            auction_house_treasury.clone(),
+        ))
+        .filter(|recipient| is_valid_recipient(recipient))
+        .or_else(|| {
+            Some(auction_house_treasury.clone()).filter(|recipient| is_valid_recipient(recipient))
+        });
+
+        if let (Some(buyer_token_account), Some(recipient)) = (buyer_token_account, recipient) {
+            let amount = punish_bots_non_native(
+                &error,
+                buyer.clone(),
+                buyer_payment_token_account,
+                buyer_token_account.amount,
+                recipient.clone(),
+                token_program,
+                bot_tax_amount,
+            )?;
+            emit!(BotTaxCollectedEvent {
+                edition_distributor: edition_distributor.key(),
+                suspect: buyer.key(),
+                recipient: recipient.key(),
+                tax_mint,
+                amount,
+                reason: error.to_string(),
+            });
+
+            return Ok(());
+        }
+    }
+
+    let is_native_treasury = auction_house.treasury_mint == spl_token::native_mint::id();
+    let recipient = match (is_native, is_native_treasury) {
+        (true, true) => get_bot_tax_recipient(
+            edition_distributor.bot_tax_recipient,
+            remaining_accounts,
+            auction_house_treasury,
+        ),
+        (true, false) => get_bot_tax_recipient(
+            edition_distributor.bot_tax_recipient,
+            remaining_accounts,
+            auction_house_fee_account,
+        ),
+        (false, _) => auction_house_fee_account,
+    };
+    let amount = punish_bots(
+        &error,
+        buyer.clone(),
+        recipient.clone(),
+        system_program,
+        if is_native {
+            edition_distributor.bot_tax_amount
+        } else {
+            None
+        },
+    )?;
+
+    emit!(BotTaxCollectedEvent {
+        edition_distributor: edition_distributor.key(),
+        suspect: buyer.key(),
+        recipient: recipient.key(),
+        tax_mint: spl_token::native_mint::id(),
+        amount,
+        reason: error.to_string(),
+    });
+
+    Ok(())
+}
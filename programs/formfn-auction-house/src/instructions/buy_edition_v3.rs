@@ -0,0 +1,54 @@
+use anchor_lang::{prelude::*, solana_program::sysvar};
+
+use crate::{
+    process_buy_edition, BuyEditionV2, BuyerMerkleAllowlistProofData, PrintEditionAccounts,
+};
+
+#[derive(Accounts)]
+pub struct BuyEditionV3<'info> {
+    buy_edition: BuyEditionV2<'info>,
+    /// Wallet which receives the edition, which may be the buyer. The buyer_token_account
+    /// and edition_buyer_info_account in buy_edition must be derived from this wallet.
+    /// CHECK: No need to deserialize.
+    recipient: UncheckedAccount<'info>,
+    /// Token record for the new edition if the master edition is programmable, otherwise
+    /// the Token Metadata program id.
+    /// CHECK: Account is passed through to mpl program.
+    edition_token_record: UncheckedAccount<'info>,
+    /// CHECK: Address is checked.
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+}
+
+/**
+ * Same as buy_edition_for_recipient, except the edition is minted with Token Metadata's
+ * Print instruction, which supports programmable master editions. buy_edition_v2 keeps
+ * using the deprecated mint_new_edition_from_master_edition_via_token instruction for
+ * existing distributors.
+ */
+pub fn handle_buy_edition_v3<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyEditionV3<'info>>,
+    edition_bump: u8,
+    // The price the buyer pays. Validated inside this instruction
+    price_in_lamports: u64,
+    recipient_edition_info_account_bump: u8,
+    recipient_merkle_allowlist_proof_data: Option<BuyerMerkleAllowlistProofData>,
+) -> Result<()> {
+    let recipient = ctx.accounts.recipient.to_account_info();
+    let print_edition_accounts = PrintEditionAccounts {
+        edition_token_record: ctx.accounts.edition_token_record.to_account_info(),
+        sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+    };
+
+    process_buy_edition(
+        &mut ctx.accounts.buy_edition,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        recipient,
+        Some(print_edition_accounts),
+        edition_bump,
+        price_in_lamports,
+        recipient_edition_info_account_bump,
+        recipient_merkle_allowlist_proof_data,
+    )
+}
@@ -0,0 +1,386 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Token, TokenAccount},
+};
+
+use crate::{
+    constants::*, refund_previous_bid, settle_sale, utils::*, AuctionHouse, AuctionHouseError,
+    LastBidPrice, SaleAccounts, TradeStateSaleType,
+};
+
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8, free_trade_state_bump: u8, program_as_signer_bump: u8, buy_now_price: u64, seller_price: u64, token_size: u64, previous_bidder_escrow_payment_bump: u8)]
+pub struct BuyNow<'info> {
+    #[account(mut)]
+    buyer: Signer<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    payment_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    transfer_authority: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    #[account(mut)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: No need to deserialize.
+    token_mint: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize. Only needs to be writable for the primary sale to be
+    /// recorded in it, see update_primary_sale_happened_via_token.
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    treasury_mint: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = escrow_payment_bump
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    seller_payment_receipt_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    buyer_receipt_token_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_treasury,
+        has_one = auction_house_fee_account,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Box<Account<'info, AuctionHouse>>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump = auction_house.fee_payer_bump
+    )]
+    auction_house_fee_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump = auction_house.treasury_bump
+    )]
+    auction_house_treasury: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &seller_price.to_le_bytes(),
+            &token_size.to_le_bytes()
+        ],
+        bump = get_trade_state(&seller_trade_state.to_account_info())?.bump
+    )]
+    seller_trade_state: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &0u64.to_le_bytes(),
+            &token_size.to_le_bytes()
+        ],
+        bump = free_trade_state_bump
+    )]
+    free_trade_state: UncheckedAccount<'info>,
+    #[account(mut)]
+    last_bid_price: Box<Account<'info, LastBidPrice>>,
+    /// CHECK: No need to deserialize.
+    previous_bidder_wallet: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            previous_bidder_wallet.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = previous_bidder_escrow_payment_bump
+    )]
+    previous_bidder_escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    previous_bidder_refund_account: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        bump = program_as_signer_bump
+    )]
+    program_as_signer: UncheckedAccount<'info>,
+    rent: Sysvar<'info, Rent>,
+    /// CHECK: No need to deserialize.
+    master_edition: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(address = mpl_token_metadata::id())]
+    metaplex_token_metadata_program: UncheckedAccount<'info>,
+}
+
+/**
+ * Buys an auctioned token at its buy now price (see set_buy_now_price) in a single
+ * instruction. The current highest bid is refunded, the buy now price plus the buyer premium
+ * is escrowed from payment_account, and the sale is settled like execute_sale_v2. No buyer
+ * trade state is needed since the buyer signs.
+ *
+ * remaining_accounts are the same as for execute_sale_v2. If the highest bid is backed by a
+ * shared escrow reservation, the previous bidder's shared escrow is passed after them.
+ */
+pub fn handle_buy_now<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>,
+    escrow_payment_bump: u8,
+    _free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buy_now_price: u64,
+    seller_price: u64,
+    token_size: u64,
+    previous_bidder_escrow_payment_bump: u8,
+) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let payment_account = &ctx.accounts.payment_account;
+    let transfer_authority = &ctx.accounts.transfer_authority;
+    let seller = &ctx.accounts.seller;
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let seller_payment_receipt_account = &ctx.accounts.seller_payment_receipt_account;
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+    let authority = &ctx.accounts.authority;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let free_trade_state = &ctx.accounts.free_trade_state;
+    let last_bid_price = &mut ctx.accounts.last_bid_price;
+    let previous_bidder_wallet = &ctx.accounts.previous_bidder_wallet;
+    let previous_bidder_escrow_payment_account =
+        &ctx.accounts.previous_bidder_escrow_payment_account;
+    let previous_bidder_refund_account = &ctx.accounts.previous_bidder_refund_account;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let ata_program = &ctx.accounts.ata_program;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let rent = &ctx.accounts.rent;
+    let master_edition = &ctx.accounts.master_edition;
+    let metaplex_token_metadata_program = &ctx.accounts.metaplex_token_metadata_program;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
+    assert_valid_last_bid_price(
+        &last_bid_price.to_account_info(),
+        ctx.program_id,
+        &token_mint.key(),
+    )?;
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
+    if buy_now_price < seller_price {
+        return Err(AuctionHouseError::MismatchedPrices.into());
+    }
+
+    if seller_trade_state.data_is_empty() {
+        return Err(AuctionHouseError::BothPartiesNeedToAgreeToSale.into());
+    }
+
+    let seller_trade_state_data = get_trade_state(&seller_trade_state.to_account_info())?;
+    if seller_trade_state_data.bump == 0 {
+        return Err(AuctionHouseError::CanOnlyExecuteSaleOnce.into());
+    }
+
+    let seller_sale_type = assert_valid_trade_state_sale_type(seller_trade_state_data.sale_type)?;
+    msg!("seller_sale_type = {}", seller_sale_type);
+    if seller_sale_type != TradeStateSaleType::Auction {
+        return Err(AuctionHouseError::BuyNowOnlyForAuctions.into());
+    }
+
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    assert_trade_state_not_expired(&seller_trade_state_data, unix_timestamp)?;
+
+    if get_buy_now_purchase_price(last_bid_price) != Some(buy_now_price) {
+        return Err(AuctionHouseError::InvalidBuyNowPrice.into());
+    }
+
+    refund_previous_bid(
+        last_bid_price,
+        &buyer.key(),
+        None,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        previous_bidder_wallet,
+        previous_bidder_refund_account,
+        previous_bidder_escrow_payment_account,
+        authority,
+        auction_house,
+        auction_house_fee_account,
+        treasury_mint,
+        token_mint,
+        system_program,
+        token_program,
+        ata_program,
+        rent,
+        previous_bidder_escrow_payment_bump,
+    )?;
+    last_bid_price.bidder_uses_shared_escrow = false;
+
+    // The buyer premium is charged at the current rate, like for a bid placed now.
+    let buyer_premium = get_buyer_premium(buy_now_price, auction_house.buyer_premium_basis_points);
+    let escrow_amount = buy_now_price
+        .checked_add(buyer_premium)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    let auction_house_key = auction_house.key();
+    let seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        FEE_PAYER.as_bytes(),
+        &[auction_house.fee_payer_bump],
+    ];
+
+    let (fee_payer, fee_seeds) = get_fee_payer(
+        authority,
+        auction_house,
+        buyer.to_account_info(),
+        auction_house_fee_account.to_account_info(),
+        &seeds,
+    )?;
+
+    let buyer_key = buyer.key();
+    let token_mint_key = token_mint.key();
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer_key.as_ref(),
+        token_mint_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+
+    create_program_token_account_if_not_present(
+        escrow_payment_account,
+        system_program,
+        &fee_payer,
+        token_program,
+        treasury_mint,
+        &auction_house.to_account_info(),
+        rent,
+        &escrow_signer_seeds,
+        fee_seeds,
+        is_native,
+    )?;
+
+    if is_native {
+        assert_keys_equal(buyer.key(), payment_account.key())?;
+
+        invoke(
+            &system_instruction::transfer(
+                &payment_account.key(),
+                &escrow_payment_account.key(),
+                escrow_amount,
+            ),
+            &[
+                payment_account.to_account_info(),
+                escrow_payment_account.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    } else {
+        invoke(
+            &spl_token::instruction::transfer(
+                &token_program.key(),
+                &payment_account.key(),
+                &escrow_payment_account.key(),
+                &transfer_authority.key(),
+                &[],
+                escrow_amount,
+            )?,
+            &[
+                transfer_authority.to_account_info(),
+                payment_account.to_account_info(),
+                escrow_payment_account.to_account_info(),
+                token_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    settle_sale(
+        &SaleAccounts {
+            buyer: buyer.to_account_info(),
+            seller: seller.to_account_info(),
+            token_account,
+            token_mint: token_mint.to_account_info(),
+            metadata: metadata.to_account_info(),
+            treasury_mint: treasury_mint.to_account_info(),
+            seller_payment_receipt_account: seller_payment_receipt_account.to_account_info(),
+            buyer_receipt_token_account: buyer_receipt_token_account.to_account_info(),
+            escrow_payment_account: escrow_payment_account.to_account_info(),
+            authority,
+            auction_house,
+            auction_house_fee_account: auction_house_fee_account.to_account_info(),
+            auction_house_treasury: auction_house_treasury.to_account_info(),
+            buyer_trade_state: None,
+            seller_trade_state: seller_trade_state.to_account_info(),
+            free_trade_state: free_trade_state.to_account_info(),
+            token_program: token_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+            ata_program: ata_program.to_account_info(),
+            program_as_signer: program_as_signer.to_account_info(),
+            rent: rent.to_account_info(),
+            master_edition: master_edition.to_account_info(),
+            metaplex_token_metadata_program: metaplex_token_metadata_program.to_account_info(),
+        },
+        last_bid_price,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        escrow_payment_bump,
+        program_as_signer_bump,
+        buy_now_price,
+        buyer_premium,
+        token_size,
+        unix_timestamp,
+    )
+}
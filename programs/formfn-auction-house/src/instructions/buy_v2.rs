@@ -8,7 +8,8 @@ use anchor_spl::{
 };
 
 use crate::{
-    constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice, TradeStateSaleType,
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice, SharedEscrow,
+    TradeState, TradeStateSaleType, TRADE_STATE_SIZE, TRADE_STATE_VERSION,
 };
 
 // Supports on-chain refunds
@@ -118,39 +119,81 @@ pub fn handle_buy_v2<'info>(
     auction_end_time: Option<i64>,
     previous_bidder_escrow_payment_bump: u8,
 ) -> Result<()> {
-    let wallet = &ctx.accounts.wallet;
-    let payment_account = &ctx.accounts.payment_account;
-    let transfer_authority = &ctx.accounts.transfer_authority;
-    let treasury_mint = &ctx.accounts.treasury_mint;
-    let metadata = &ctx.accounts.metadata;
-    let token_account = &ctx.accounts.token_account;
-    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
-    let authority = &ctx.accounts.authority;
-    let auction_house = &ctx.accounts.auction_house;
-    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
-    let buyer_trade_state = &mut ctx.accounts.buyer_trade_state;
-    let token_mint = &ctx.accounts.token_mint;
-    let last_bid_price = &mut ctx.accounts.last_bid_price;
-    let token_program = &ctx.accounts.token_program;
-    let system_program = &ctx.accounts.system_program;
-    let rent = &ctx.accounts.rent;
-    let clock = &ctx.accounts.clock;
+    process_buy_v2(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        None,
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+        auction_end_time,
+        previous_bidder_escrow_payment_bump,
+        None,
+    )
+}
+
+/**
+ * If shared_escrow is provided the bid is backed by a reservation on it instead of being
+ * transferred to the escrow payment account. If the previous bid is backed by a shared
+ * escrow reservation, that reservation is released instead of refunding the previous bidder,
+ * and the previous bidder's shared escrow must be passed in remaining_accounts.
+ *
+ * If proxy_bid_price is provided, buyer_price is a max bid which is escrowed in full, and
+ * proxy_bid_price is the lowest visible price the bidder asks for, see resolve_auction_bid.
+ *
+ * Auction bids also escrow the auction house's buyer premium on top of buyer_price, which is
+ * refunded along with the bid if it is outbid.
+ */
+pub fn process_buy_v2<'info>(
+    accounts: &mut BuyV2<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    mut shared_escrow: Option<&mut Account<'info, SharedEscrow>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    auction_end_time: Option<i64>,
+    previous_bidder_escrow_payment_bump: u8,
+    proxy_bid_price: Option<u64>,
+) -> Result<()> {
+    let wallet = &accounts.wallet;
+    let payment_account = &accounts.payment_account;
+    let transfer_authority = &accounts.transfer_authority;
+    let treasury_mint = &accounts.treasury_mint;
+    let metadata = &accounts.metadata;
+    let token_account = &accounts.token_account;
+    let escrow_payment_account = &accounts.escrow_payment_account;
+    let authority = &accounts.authority;
+    let auction_house = &accounts.auction_house;
+    let auction_house_fee_account = &accounts.auction_house_fee_account;
+    let buyer_trade_state = &accounts.buyer_trade_state;
+    let token_mint = &accounts.token_mint;
+    let last_bid_price = &mut accounts.last_bid_price;
+    let token_program = &accounts.token_program;
+    let system_program = &accounts.system_program;
+    let rent = &accounts.rent;
+    let clock = &accounts.clock;
 
-    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_valid_auction_house(program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
     assert_valid_last_bid_price(
         &last_bid_price.to_account_info(),
-        ctx.program_id,
+        program_id,
         &token_mint.key(),
     )?;
 
     let is_native = treasury_mint.key() == spl_token::native_mint::id();
-    let previous_bidder_wallet = &ctx.accounts.previous_bidder_wallet;
-    let previous_bidder_refund_account = &ctx.accounts.previous_bidder_refund_account;
+    let previous_bidder_wallet = &accounts.previous_bidder_wallet;
+    let previous_bidder_refund_account = &accounts.previous_bidder_refund_account;
     let previous_bidder_escrow_payment_account =
-        &ctx.accounts.previous_bidder_escrow_payment_account;
-    let ata_program = &ctx.accounts.ata_program;
+        &accounts.previous_bidder_escrow_payment_account;
+    let ata_program = &accounts.ata_program;
 
-    let sale_type = get_trade_state_sale_type(&buyer_trade_state.to_account_info());
+    let sale_type =
+        get_sell_or_buy_sale_type(&buyer_trade_state.to_account_info(), clock.unix_timestamp)?;
     msg!("buyer_sale_type = {}", sale_type);
 
     match auction_end_time {
@@ -170,6 +213,45 @@ pub fn handle_buy_v2<'info>(
         return Err(AuctionHouseError::InvalidTokenAmount.into());
     }
 
+    // Auction bids are resolved before any funds move, since a bid which is countered by the
+    // standing proxy bid doesn't become the highest bid and isn't escrowed.
+    let auction_bid_price = match sale_type {
+        TradeStateSaleType::Auction => {
+            last_bid_price.buy_now_price =
+                get_active_buy_now_price(last_bid_price.buy_now_price, last_bid_price.price);
+            let tick_size =
+                get_effective_tick_size(last_bid_price, &auction_house.default_tick_size);
+            let outcome = resolve_auction_bid(
+                last_bid_price,
+                &wallet.key(),
+                proxy_bid_price.unwrap_or(buyer_price),
+                buyer_price,
+                &tick_size,
+                treasury_mint.decimals,
+            )?;
+            if !outcome.is_highest_bid {
+                msg!("Bid countered by proxy bid, price = {}", outcome.price);
+                last_bid_price.price = outcome.price;
+                return Ok(());
+            }
+            outcome.price
+        }
+        _ if proxy_bid_price.is_some() => {
+            return Err(AuctionHouseError::MaxBidOnlyForAuctions.into());
+        }
+        _ => buyer_price,
+    };
+
+    // Auction bids escrow the buyer premium on top of the bid, see
+    // set_buyer_premium_basis_points.
+    let buyer_premium_basis_points = match sale_type {
+        TradeStateSaleType::Auction => auction_house.buyer_premium_basis_points,
+        _ => 0,
+    };
+    let escrow_amount = buyer_price
+        .checked_add(get_buyer_premium(buyer_price, buyer_premium_basis_points))
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
     let auction_house_key = auction_house.key();
     let seeds = [
         PREFIX.as_bytes(),
@@ -210,14 +292,25 @@ pub fn handle_buy_v2<'info>(
         is_native,
     )?;
 
-    if is_native {
+    if let Some(shared_escrow) = shared_escrow.as_deref() {
+        // The bid is reserved on the shared escrow below, after the previous bid (which may
+        // be this wallet's own) has been released.
+        assert_keys_equal(shared_escrow.wallet, wallet.key())?;
+        assert_keys_equal(shared_escrow.auction_house, auction_house.key())?;
+        if !is_native {
+            return Err(AuctionHouseError::SharedEscrowRequiresNativeMint.into());
+        }
+        if sale_type != TradeStateSaleType::Auction {
+            return Err(AuctionHouseError::SharedEscrowOnlyForAuctions.into());
+        }
+    } else if is_native {
         assert_keys_equal(wallet.key(), payment_account.key())?;
 
         invoke(
             &system_instruction::transfer(
                 &payment_account.key(),
                 &escrow_payment_account.key(),
-                buyer_price,
+                escrow_amount,
             ),
             &[
                 payment_account.to_account_info(),
@@ -233,7 +326,7 @@ pub fn handle_buy_v2<'info>(
                 &escrow_payment_account.key(),
                 &transfer_authority.key(),
                 &[],
-                buyer_price,
+                escrow_amount,
             )?,
             &[
                 transfer_authority.to_account_info(),
@@ -262,130 +355,163 @@ pub fn handle_buy_v2<'info>(
             &[trade_state_bump],
         ];
         create_or_allocate_account_raw(
-            *ctx.program_id,
+            *program_id,
             &ts_info,
             &rent.to_account_info(),
             &system_program,
             &fee_payer,
-            1 as usize,
+            TRADE_STATE_SIZE,
             fee_seeds,
             &ts_seeds,
         )?;
+        write_trade_state(
+            &ts_info,
+            &TradeState {
+                bump: trade_state_bump,
+                version: TRADE_STATE_VERSION,
+                sale_type: TradeStateSaleType::Auction as u8,
+                seller: token_account.owner,
+                buyer: wallet_key,
+                token_mint: token_mint.key(),
+                price: buyer_price,
+                token_size,
+                created_at: clock.unix_timestamp,
+                expiry: None,
+            },
+        )?;
     }
-    let buyer_trade_state_data_len = ts_info.data_len();
-    let buyer_trade_state_data = &mut ts_info.data.borrow_mut();
-    buyer_trade_state_data[0] = trade_state_bump;
 
     // Execute various checks and other business logic based on the type of sale
-    if buyer_trade_state_data_len > 1 {
-        // Manually "deserialize" data here instead of relying on anchor since we
-        // use `create_or_allocate_account_raw` to initialize these accounts using a
-        // dynamic fee_payer (vs. Anchor where we need to specify fee_payer)
-        let buyer_sale_type = buyer_trade_state_data[1];
-
-        // https://stackoverflow.com/a/28029667
-        match buyer_sale_type {
-            sale_type if sale_type == TradeStateSaleType::Auction as u8 => {
-                if last_bid_price.price > 0 {
-                    let min_price_diff = get_min_price_diff_in_lamports(
-                        last_bid_price.price,
-                        last_bid_price.tick_size_constant_in_lamports,
-                        treasury_mint.decimals,
-                    )?;
-                    let min_price = last_bid_price
-                        .price
-                        .checked_add(min_price_diff)
-                        .ok_or(AuctionHouseError::NumericalOverflow)?;
-                    if buyer_price < min_price {
-                        return Err(AuctionHouseError::BidTooLow.into());
-                    }
-                }
-
-                if let Some(last_bid_price_bidder) = last_bid_price.bidder {
-                    if last_bid_price.price > 0 && last_bid_price_bidder != ZERO_PUBKEY {
-                        if previous_bidder_wallet.key() != last_bid_price_bidder {
-                            return Err(AuctionHouseError::PreviousBidderIncorrect.into());
-                        }
-                        withdraw_helper(
-                            &UncheckedAccount::try_from(previous_bidder_wallet.to_account_info()),
-                            previous_bidder_refund_account,
-                            previous_bidder_escrow_payment_account,
-                            authority,
-                            auction_house,
-                            auction_house_fee_account,
-                            &treasury_mint.to_account_info(),
-                            token_mint,
-                            system_program,
-                            token_program,
-                            ata_program,
-                            rent,
-                            previous_bidder_escrow_payment_bump,
-                            last_bid_price.price,
-                            false,
-                        )?;
-                    }
-                }
+    match sale_type {
+        TradeStateSaleType::Auction => {
+            refund_previous_bid(
+                last_bid_price,
+                &wallet.key(),
+                shared_escrow.as_deref_mut(),
+                remaining_accounts,
+                program_id,
+                previous_bidder_wallet,
+                previous_bidder_refund_account,
+                previous_bidder_escrow_payment_account,
+                authority,
+                auction_house,
+                auction_house_fee_account,
+                &treasury_mint.to_account_info(),
+                token_mint,
+                system_program,
+                token_program,
+                ata_program,
+                rent,
+                previous_bidder_escrow_payment_bump,
+            )?;
 
-                // Only set last bid price if this is for an auction
-                last_bid_price.price = buyer_price;
-                last_bid_price.bidder = Some(wallet.key());
-            }
-            sale_type if sale_type == TradeStateSaleType::Offer as u8 => {
-                if last_bid_price.price > 0 || last_bid_price.bidder != Some(ZERO_PUBKEY) {
-                    // If sale type for buy is not auction and auction is already
-                    // in progress, do not allow
-                    return Err(AuctionHouseError::CannotPlaceOfferWhileOnAuction.into());
-                }
-            }
-            _ => {
-                // Do nothing
+            if let Some(shared_escrow) = shared_escrow.as_deref_mut() {
+                reserve_shared_escrow_amount(shared_escrow, escrow_amount)?;
             }
+
+            // Only set last bid price if this is for an auction
+            last_bid_price.price = auction_bid_price;
+            last_bid_price.proxy_max_bid = if proxy_bid_price.is_some() {
+                buyer_price
+            } else {
+                0
+            };
+            last_bid_price.buyer_premium_basis_points = buyer_premium_basis_points;
+            last_bid_price.bidder = Some(wallet.key());
+            last_bid_price.bidder_uses_shared_escrow = shared_escrow.is_some();
         }
-    } else {
-        // Keep legacy logic around to not break old listings
-        if last_bid_price.price > 0 {
-            let min_price_diff = get_min_price_diff_in_lamports(
-                last_bid_price.price,
-                last_bid_price.tick_size_constant_in_lamports,
-                treasury_mint.decimals,
-            )?;
-            let min_price = last_bid_price
-                .price
-                .checked_add(min_price_diff)
-                .ok_or(AuctionHouseError::NumericalOverflow)?;
-            if buyer_price < min_price {
-                return Err(AuctionHouseError::BidTooLow.into());
+        TradeStateSaleType::Offer => {
+            if last_bid_price.price > 0 || last_bid_price.bidder != Some(ZERO_PUBKEY) {
+                // If sale type for buy is not auction and auction is already
+                // in progress, do not allow
+                return Err(AuctionHouseError::CannotPlaceOfferWhileOnAuction.into());
             }
         }
+        TradeStateSaleType::InstantSale => {
+            // Do nothing
+        }
+        TradeStateSaleType::SealedBidAuction => {
+            return Err(AuctionHouseError::SealedBidAuctionRequiresCommit.into());
+        }
+    }
 
-        if let Some(last_bid_price_bidder) = last_bid_price.bidder {
-            if last_bid_price.price > 0 && last_bid_price_bidder != ZERO_PUBKEY {
-                if previous_bidder_wallet.key() != last_bid_price_bidder {
-                    return Err(AuctionHouseError::PreviousBidderIncorrect.into());
+    Ok(())
+}
+
+/**
+ * Refunds the current auction bid in full, including its buyer premium and the unused part of
+ * a max bid. A bid backed by a shared escrow reservation is released instead. The previous
+ * bidder's shared escrow is passed in remaining_accounts, unless wallet is raising its own bid
+ * and shared_escrow is provided. Shared by process_buy_v2 and buy_now.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn refund_previous_bid<'info>(
+    last_bid_price: &LastBidPrice,
+    wallet: &Pubkey,
+    mut shared_escrow: Option<&mut Account<'info, SharedEscrow>>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    previous_bidder_wallet: &UncheckedAccount<'info>,
+    previous_bidder_refund_account: &UncheckedAccount<'info>,
+    previous_bidder_escrow_payment_account: &UncheckedAccount<'info>,
+    authority: &UncheckedAccount<'info>,
+    auction_house: &Account<'info, AuctionHouse>,
+    auction_house_fee_account: &UncheckedAccount<'info>,
+    treasury_mint: &AccountInfo<'info>,
+    token_mint: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    token_program: &Program<'info, Token>,
+    ata_program: &Program<'info, AssociatedToken>,
+    rent: &Sysvar<'info, Rent>,
+    previous_bidder_escrow_payment_bump: u8,
+) -> Result<()> {
+    let previous_bid_amount = get_escrowed_bid_amount(last_bid_price);
+    if let Some(last_bid_price_bidder) = last_bid_price.bidder {
+        if last_bid_price.price > 0 && last_bid_price_bidder != ZERO_PUBKEY {
+            if previous_bidder_wallet.key() != last_bid_price_bidder {
+                return Err(AuctionHouseError::PreviousBidderIncorrect.into());
+            }
+            if last_bid_price.bidder_uses_shared_escrow {
+                match shared_escrow.as_deref_mut() {
+                    // The wallet is raising its own bid.
+                    Some(shared_escrow) if last_bid_price_bidder == *wallet => {
+                        release_shared_escrow_amount(shared_escrow, previous_bid_amount)?;
+                    }
+                    _ => {
+                        let mut previous_bidder_shared_escrow =
+                            get_shared_escrow_from_remaining_accounts(
+                                remaining_accounts,
+                                program_id,
+                                &auction_house.key(),
+                                &last_bid_price_bidder,
+                            )?;
+                        release_shared_escrow_amount(
+                            &mut previous_bidder_shared_escrow,
+                            previous_bid_amount,
+                        )?;
+                        previous_bidder_shared_escrow.exit(program_id)?;
+                    }
                 }
+            } else {
                 withdraw_helper(
-                    &UncheckedAccount::try_from(previous_bidder_wallet.to_account_info()),
+                    previous_bidder_wallet,
                     previous_bidder_refund_account,
                     previous_bidder_escrow_payment_account,
                     authority,
                     auction_house,
                     auction_house_fee_account,
-                    &treasury_mint.to_account_info(),
+                    treasury_mint,
                     token_mint,
                     system_program,
                     token_program,
                     ata_program,
                     rent,
                     previous_bidder_escrow_payment_bump,
-                    last_bid_price.price,
+                    previous_bid_amount,
                     false,
                 )?;
             }
         }
-
-        last_bid_price.price = buyer_price;
-        last_bid_price.bidder = Some(wallet.key());
     }
-
     Ok(())
 }
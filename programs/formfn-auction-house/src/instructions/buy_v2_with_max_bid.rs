@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{process_buy_v2, BuyV2};
+
+#[derive(Accounts)]
+pub struct BuyV2WithMaxBid<'info> {
+    buy: BuyV2<'info>,
+}
+
+/**
+ * Same as buy_v2, except buyer_price is a max bid which is escrowed in full, and bid_price
+ * is the lowest visible price the bidder asks for. The visible price (LastBidPrice.price) is
+ * only raised as far as needed to beat the previous bid, and later bids are automatically
+ * countered up to the max bid within their own instruction. Only supported for auctions.
+ *
+ * Whatever part of the max bid is not needed to pay for the sale stays in the escrow payment
+ * account and can be withdrawn by the buyer.
+ */
+pub fn handle_buy_v2_with_max_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyV2WithMaxBid<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    // Unix time (seconds since epoch)
+    auction_end_time: Option<i64>,
+    previous_bidder_escrow_payment_bump: u8,
+    bid_price: u64,
+) -> Result<()> {
+    process_buy_v2(
+        &mut ctx.accounts.buy,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        None,
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+        auction_end_time,
+        previous_bidder_escrow_payment_bump,
+        Some(bid_price),
+    )
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{process_buy_v2, BuyV2, SharedEscrow};
+
+#[derive(Accounts)]
+pub struct BuyV2WithSharedEscrow<'info> {
+    buy: BuyV2<'info>,
+    /// The wallet's shared escrow, validated in process_buy_v2.
+    #[account(mut)]
+    shared_escrow: Account<'info, SharedEscrow>,
+}
+
+/**
+ * Same as buy_v2, except the bid is backed by a reservation on the wallet's shared escrow
+ * instead of being transferred to the escrow payment account for this mint. The reservation
+ * is released if the bid is outbid, and must be settled with settle_shared_escrow_reservation
+ * before the sale is executed. Only supported for auctions on native auction houses.
+ */
+pub fn handle_buy_v2_with_shared_escrow<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyV2WithSharedEscrow<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    // Unix time (seconds since epoch)
+    auction_end_time: Option<i64>,
+    previous_bidder_escrow_payment_bump: u8,
+) -> Result<()> {
+    process_buy_v2(
+        &mut ctx.accounts.buy,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        Some(&mut ctx.accounts.shared_escrow),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+        auction_end_time,
+        previous_bidder_escrow_payment_bump,
+        None,
+    )
+}
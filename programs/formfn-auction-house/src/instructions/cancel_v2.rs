@@ -51,7 +51,7 @@ pub struct CancelV2<'info> {
             &buyer_price.to_le_bytes(),
             &token_size.to_le_bytes()
         ],
-        bump = trade_state.to_account_info().data.borrow()[0]
+        bump = get_trade_state(&trade_state.to_account_info())?.bump
     )]
     trade_state: UncheckedAccount<'info>,
     token_program: Program<'info, Token>,
@@ -93,7 +93,10 @@ pub fn handle_cancel_v2(
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
 
     assert_keys_equal(token_mint.key(), token_account.mint)?;
-    let sale_type = get_trade_state_sale_type(&trade_state.to_account_info());
+    // Expired trade states can always be cancelled.
+    let sale_type = assert_valid_trade_state_sale_type(
+        get_trade_state(&trade_state.to_account_info())?.sale_type,
+    )?;
     msg!("sale_type = {}", sale_type);
 
     if !wallet.to_account_info().is_signer && !authority.to_account_info().is_signer {
@@ -0,0 +1,119 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::Token;
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, ClaimableBalance};
+
+#[derive(Accounts)]
+pub struct ClaimBalance<'info> {
+    #[account(mut)]
+    recipient: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        has_one = recipient,
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            recipient.key().as_ref(),
+            CLAIMABLE_BALANCE.as_bytes()
+        ],
+        bump = claimable_balance.bump
+    )]
+    claimable_balance: Account<'info, ClaimableBalance>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * Withdraws everything credited to the recipient's ClaimableBalance by execute_sale_v2.
+ *
+ * For SPL auction houses the ClaimableBalance's associated token account and the recipient's
+ * associated token account must be passed in remaining_accounts.
+ */
+pub fn handle_claim_balance<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimBalance<'info>>,
+) -> Result<()> {
+    let recipient = &ctx.accounts.recipient;
+    let auction_house = &ctx.accounts.auction_house;
+    let claimable_balance = &mut ctx.accounts.claimable_balance;
+    let token_program = &ctx.accounts.token_program;
+
+    let amount = claimable_balance.amount;
+    if amount == 0 {
+        return Err(AuctionHouseError::NothingToClaim.into());
+    }
+    claimable_balance.amount = 0;
+
+    if auction_house.treasury_mint == spl_token::native_mint::id() {
+        let claimable_balance_info = claimable_balance.to_account_info();
+        **claimable_balance_info.lamports.borrow_mut() = claimable_balance_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+        **recipient.lamports.borrow_mut() = recipient
+            .lamports()
+            .checked_add(amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+        return Ok(());
+    }
+
+    let claimable_balance_key = claimable_balance.key();
+    let claimable_balance_token_account_key =
+        get_associated_token_address(&claimable_balance_key, &auction_house.treasury_mint);
+    let recipient_token_account_key =
+        get_associated_token_address(&recipient.key(), &auction_house.treasury_mint);
+    let claimable_balance_token_account = ctx
+        .remaining_accounts
+        .iter()
+        .find(|account| account.key() == claimable_balance_token_account_key)
+        .ok_or(AuctionHouseError::ClaimableBalanceNotProvided)?;
+    let recipient_token_account = ctx
+        .remaining_accounts
+        .iter()
+        .find(|account| account.key() == recipient_token_account_key)
+        .ok_or(AuctionHouseError::ClaimableBalanceNotProvided)?;
+    assert_is_ata(
+        recipient_token_account,
+        &recipient.key(),
+        &auction_house.treasury_mint,
+    )?;
+
+    let auction_house_key = auction_house.key();
+    let recipient_key = recipient.key();
+    let claimable_balance_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        recipient_key.as_ref(),
+        CLAIMABLE_BALANCE.as_bytes(),
+        &[claimable_balance.bump],
+    ];
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            &token_program.key(),
+            &claimable_balance_token_account_key,
+            &recipient_token_account_key,
+            &claimable_balance_key,
+            &[],
+            amount,
+        )?,
+        &[
+            claimable_balance_token_account.clone(),
+            recipient_token_account.clone(),
+            claimable_balance.to_account_info(),
+            token_program.to_account_info(),
+        ],
+        &[&claimable_balance_seeds],
+    )?;
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, AuctionHouse, EditionAllowlistSettings, EditionDistributor};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseRole, EditionAllowlistSettings,
+    EditionDistributor,
+};
 
 #[derive(Accounts)]
 pub struct ClearEditionAllowlistMerkleRoots<'info> {
@@ -25,7 +28,6 @@ pub struct ClearEditionAllowlistMerkleRoots<'info> {
     )]
     edition_allowlist_settings: Account<'info, EditionAllowlistSettings>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -39,7 +41,16 @@ pub struct ClearEditionAllowlistMerkleRoots<'info> {
 pub fn handle_clear_edition_allowlist_merkle_roots<'info>(
     ctx: Context<'_, '_, '_, 'info, ClearEditionAllowlistMerkleRoots<'info>>,
 ) -> Result<()> {
+    assert_authority_or_role(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::DropManager,
+    )?;
+
     let edition_allowlist_settings = &mut ctx.accounts.edition_allowlist_settings;
+    assert_edition_allowlist_settings_migrated(&edition_allowlist_settings.to_account_info())?;
 
     let existing_root_list_length = edition_allowlist_settings.merkle_roots.len();
 
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, AuctionHouse, EditionAllowlistSettings, EditionDistributor};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseRole, EditionAllowlistSettings,
+    EditionDistributor,
+};
 
 #[derive(Accounts)]
 pub struct CloseEditionAllowlistSettingsAccount<'info> {
@@ -26,7 +29,6 @@ pub struct CloseEditionAllowlistSettingsAccount<'info> {
     )]
     edition_allowlist_settings: Account<'info, EditionAllowlistSettings>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -41,8 +43,15 @@ pub struct CloseEditionAllowlistSettingsAccount<'info> {
 }
 
 pub fn handle_close_edition_allowlist_settings_account<'info>(
-    _ctx: Context<'_, '_, '_, 'info, CloseEditionAllowlistSettingsAccount<'info>>,
+    ctx: Context<'_, '_, '_, 'info, CloseEditionAllowlistSettingsAccount<'info>>,
 ) -> Result<()> {
-    // Nothing to do here yet.
+    assert_authority_or_role(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::DropManager,
+    )?;
+
     Ok(())
 }
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, EditionDistributor};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, EditionDistributor,
+};
 
 #[derive(Accounts)]
 pub struct CloseEditionDistributor<'info> {
@@ -24,7 +26,6 @@ pub struct CloseEditionDistributor<'info> {
     /// CHECK: No need to deserialize.
     authority: UncheckedAccount<'info>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -45,11 +46,22 @@ pub fn handle_close_edition_distributor<'info>(
     let owner = &ctx.accounts.owner;
     let authority = &ctx.accounts.authority;
     let auction_house = &ctx.accounts.auction_house;
+    let edition_distributor = &ctx.accounts.edition_distributor;
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
 
-    if !owner.to_account_info().is_signer && !authority.to_account_info().is_signer {
-        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::DropManager,
+        )?;
+    }
+
+    if edition_distributor.is_supply_locked {
+        return Err(AuctionHouseError::EditionSupplyLocked.into());
     }
 
     Ok(())
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, EditionDistributor};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, EditionDistributor,
+};
 
 #[derive(Accounts)]
 pub struct CloseEditionDistributorTokenAccount<'info> {
@@ -28,8 +30,7 @@ pub struct CloseEditionDistributorTokenAccount<'info> {
             auction_house.creator.as_ref(),
             auction_house.treasury_mint.as_ref()
         ],
-        bump = auction_house.bump,
-        has_one = authority
+        bump = auction_house.bump
     )]
     auction_house: Account<'info, AuctionHouse>,
     #[account(
@@ -64,8 +65,19 @@ pub fn handle_close_edition_distributor_token_account<'info>(
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
 
-    if !owner.to_account_info().is_signer && !authority.to_account_info().is_signer {
-        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::DropManager,
+        )?;
+    }
+
+    if edition_distributor.is_supply_locked {
+        // The master edition stays with the distributor once its supply is locked.
+        return Err(AuctionHouseError::EditionSupplyLocked.into());
     }
 
     assert_keys_equal(
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseRole, FeeOverride};
+
+#[derive(Accounts)]
+pub struct CloseFeeOverride<'info> {
+    authority: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            fee_override.key.as_ref(),
+            FEE_OVERRIDE.as_bytes()
+        ],
+        bump = fee_override.bump,
+    )]
+    fee_override: Account<'info, FeeOverride>,
+    /// Who receives the remaining rent allocation.
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    rent_receiver: UncheckedAccount<'info>,
+}
+
+/**
+ * Removes a fee override, so the auction house's platform fees apply again.
+ */
+pub fn handle_close_fee_override(ctx: Context<CloseFeeOverride>) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::FeeManager,
+    )?;
+
+    Ok(())
+}
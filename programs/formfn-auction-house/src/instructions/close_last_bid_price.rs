@@ -0,0 +1,107 @@
+use anchor_lang::{prelude::*, solana_program::program_option::COption};
+use anchor_spl::token::{Mint, TokenAccount};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice};
+
+#[derive(Accounts)]
+#[instruction(program_as_signer_bump: u8)]
+pub struct CloseLastBidPrice<'info> {
+    /// Receives the rent. Must match last_bid_price.rent_payer if it is set.
+    /// CHECK: Validated in the instruction handler.
+    #[account(mut)]
+    rent_receiver: UncheckedAccount<'info>,
+    token_mint: Account<'info, Mint>,
+    /// The token account which currently holds the NFT.
+    #[account(
+        constraint = token_account.mint == token_mint.key(),
+        constraint = token_account.amount == 1,
+    )]
+    token_account: Account<'info, TokenAccount>,
+    /// CHECK: Validated in the instruction handler.
+    metadata: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        bump = program_as_signer_bump
+    )]
+    program_as_signer: UncheckedAccount<'info>,
+    /// CHECK: Validated in the instruction handler.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [
+            LAST_BID_PRICE.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    last_bid_price: Account<'info, LastBidPrice>,
+}
+
+/**
+ * Closes a LastBidPrice account to reclaim its rent. This is only allowed when no auction
+ * is live, i.e. there is no bid and the NFT is not listed (sell delegates the token account
+ * to program_as_signer and freezes it). The account can be recreated later with
+ * create_last_bid_price, so accounts holding settings which that would lose cannot be
+ * closed, see assert_last_bid_price_closable.
+ *
+ * The rent goes back to whoever created the account, and either they or the auction house
+ * authority must sign. Accounts created before rent_payer was recorded can only be closed
+ * by the authority.
+ */
+pub fn handle_close_last_bid_price<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseLastBidPrice<'info>>,
+    _program_as_signer_bump: u8,
+) -> Result<()> {
+    let rent_receiver = &ctx.accounts.rent_receiver;
+    let token_account = &ctx.accounts.token_account;
+    let metadata = &ctx.accounts.metadata;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let authority = &ctx.accounts.authority;
+    let auction_house = &ctx.accounts.auction_house;
+    let last_bid_price = &ctx.accounts.last_bid_price;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    match last_bid_price.rent_payer {
+        Some(rent_payer) => {
+            assert_keys_equal(rent_receiver.key(), rent_payer)?;
+            if !rent_receiver.is_signer && !authority.is_signer {
+                return Err(AuctionHouseError::NoValidSignerPresent.into());
+            }
+        }
+        None => {
+            if !authority.is_signer {
+                return Err(AuctionHouseError::NoValidSignerPresent.into());
+            }
+        }
+    }
+
+    if token_account.is_frozen()
+        || token_account.delegate == COption::Some(program_as_signer.key())
+    {
+        return Err(AuctionHouseError::LastBidPriceNftIsListed.into());
+    }
+
+    assert_metadata_valid(metadata, token_account)?;
+    let metadata = Metadata::from_account_info(metadata)?;
+    assert_last_bid_price_closable(last_bid_price, metadata.primary_sale_happened)?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, AuctionHouseError, SealedBidAuction};
+
+#[derive(Accounts)]
+pub struct CloseSealedBidAuction<'info> {
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    seller: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        seeds = [
+            SEALED_BID_AUCTION.as_bytes(),
+            sealed_bid_auction.auction_house.as_ref(),
+            sealed_bid_auction.token_mint.as_ref()
+        ],
+        bump = sealed_bid_auction.bump
+    )]
+    sealed_bid_auction: Account<'info, SealedBidAuction>,
+}
+
+/**
+ * Closes a sealed bid auction once its reveal phase has ended and every bid has been
+ * refunded, returning the rent to the seller. This is also needed before the same NFT can
+ * be sold in another sealed bid auction.
+ */
+pub fn handle_close_sealed_bid_auction<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseSealedBidAuction<'info>>,
+) -> Result<()> {
+    let sealed_bid_auction = &ctx.accounts.sealed_bid_auction;
+
+    if Clock::get()?.unix_timestamp < sealed_bid_auction.reveal_end_time {
+        return Err(AuctionHouseError::SealedBidAuctionRevealNotEnded.into());
+    }
+
+    if sealed_bid_auction.number_of_refunds < sealed_bid_auction.number_of_bids {
+        return Err(AuctionHouseError::SealedBidsNotRefunded.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, SealedBid, SealedBidAuction,
+    SEALED_BID_SIZE, SEALED_BID_VERSION,
+};
+
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8)]
+pub struct CommitSealedBid<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    payment_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    transfer_authority: UncheckedAccount<'info>,
+    treasury_mint: Account<'info, Mint>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = escrow_payment_bump
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump = auction_house.fee_payer_bump
+    )]
+    auction_house_fee_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    token_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = auction_house,
+        has_one = token_mint,
+        seeds = [
+            SEALED_BID_AUCTION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = sealed_bid_auction.bump
+    )]
+    sealed_bid_auction: Account<'info, SealedBidAuction>,
+    #[account(
+        init,
+        seeds = [
+            SEALED_BID.as_bytes(),
+            sealed_bid_auction.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        payer = wallet,
+        space = SEALED_BID_SIZE,
+        bump
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            sealed_bid.key().as_ref(),
+            SEALED_BID_ESCROW.as_bytes()
+        ],
+        bump
+    )]
+    sealed_bid_escrow: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+/**
+ * Places a sealed bid. The commitment is get_sealed_bid_commitment(price, salt, wallet),
+ * and the deposit (which must cover the price) is moved to the bid's escrow, which only
+ * refund_sealed_bid and execute_sale_v2 can pay out of. Since the deposit is public,
+ * bidders may deposit more than they bid to hide their price.
+ *
+ * The wallet's escrow payment account for the mint is also created if needed, since the
+ * winner's payment moves through it when the sale is executed.
+ */
+pub fn handle_commit_sealed_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, CommitSealedBid<'info>>,
+    escrow_payment_bump: u8,
+    commitment: [u8; 32],
+    deposit: u64,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let payment_account = &ctx.accounts.payment_account;
+    let transfer_authority = &ctx.accounts.transfer_authority;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let authority = &ctx.accounts.authority;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let sealed_bid_auction = &mut ctx.accounts.sealed_bid_auction;
+    let sealed_bid = &mut ctx.accounts.sealed_bid;
+    let sealed_bid_escrow = &ctx.accounts.sealed_bid_escrow;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let rent = &ctx.accounts.rent;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
+
+    if Clock::get()?.unix_timestamp >= sealed_bid_auction.commit_end_time {
+        return Err(AuctionHouseError::SealedBidCommitPhaseEnded.into());
+    }
+
+    if wallet.key() == sealed_bid_auction.seller {
+        return Err(AuctionHouseError::SellerCannotBid.into());
+    }
+
+    let auction_house_key = auction_house.key();
+    let seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        FEE_PAYER.as_bytes(),
+        &[auction_house.fee_payer_bump],
+    ];
+    let (fee_payer, fee_seeds) = get_fee_payer(
+        authority,
+        auction_house,
+        wallet.to_account_info(),
+        auction_house_fee_account.to_account_info(),
+        &seeds,
+    )?;
+
+    let wallet_key = wallet.key();
+    let token_mint_key = token_mint.key();
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet_key.as_ref(),
+        token_mint_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
+    create_program_token_account_if_not_present(
+        escrow_payment_account,
+        system_program,
+        &fee_payer,
+        token_program,
+        &treasury_mint.to_account_info(),
+        &auction_house.to_account_info(),
+        rent,
+        &escrow_signer_seeds,
+        fee_seeds,
+        is_native,
+    )?;
+
+    let sealed_bid_key = sealed_bid.key();
+    let sealed_bid_escrow_bump = *ctx.bumps.get("sealed_bid_escrow").unwrap();
+    let sealed_bid_escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        sealed_bid_key.as_ref(),
+        SEALED_BID_ESCROW.as_bytes(),
+        &[sealed_bid_escrow_bump],
+    ];
+
+    create_program_token_account_if_not_present(
+        sealed_bid_escrow,
+        system_program,
+        &fee_payer,
+        token_program,
+        &treasury_mint.to_account_info(),
+        &auction_house.to_account_info(),
+        rent,
+        &sealed_bid_escrow_signer_seeds,
+        fee_seeds,
+        is_native,
+    )?;
+
+    if is_native {
+        assert_keys_equal(wallet.key(), payment_account.key())?;
+        invoke(
+            &system_instruction::transfer(
+                &payment_account.key(),
+                &sealed_bid_escrow.key(),
+                deposit,
+            ),
+            &[
+                payment_account.to_account_info(),
+                sealed_bid_escrow.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    } else {
+        assert_is_ata(payment_account, &wallet.key(), &treasury_mint.key())?;
+        invoke(
+            &spl_token::instruction::transfer(
+                &token_program.key(),
+                &payment_account.key(),
+                &sealed_bid_escrow.key(),
+                &transfer_authority.key(),
+                &[],
+                deposit,
+            )?,
+            &[
+                transfer_authority.to_account_info(),
+                payment_account.to_account_info(),
+                sealed_bid_escrow.to_account_info(),
+                token_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    sealed_bid.sealed_bid_auction = sealed_bid_auction.key();
+    sealed_bid.bidder = wallet.key();
+    sealed_bid.commitment = commitment;
+    sealed_bid.deposit = deposit;
+    sealed_bid.bump = *ctx.bumps.get("sealed_bid").unwrap();
+    sealed_bid.version = SEALED_BID_VERSION;
+    sealed_bid.escrow_bump = sealed_bid_escrow_bump;
+
+    sealed_bid_auction.number_of_bids = sealed_bid_auction
+        .number_of_bids
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use anchor_spl::{
     token::{Mint, Token},
 };
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, AUCTION_HOUSE_SIZE};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AUCTION_HOUSE_SIZE,
+    AUCTION_HOUSE_VERSION,
+};
 
 #[derive(Accounts)]
 #[instruction(bump: u8, fee_payer_bump: u8, treasury_bump: u8)]
@@ -105,6 +108,7 @@ pub fn handle_create_auction_house(
     auction_house.treasury_withdrawal_destination = treasury_withdrawal_destination.key();
     auction_house.fee_withdrawal_destination = fee_withdrawal_destination.key();
     auction_house.pay_all_fees = pay_all_fees;
+    auction_house.version = AUCTION_HOUSE_VERSION;
 
     let is_native = treasury_mint.key() == spl_token::native_mint::id();
 
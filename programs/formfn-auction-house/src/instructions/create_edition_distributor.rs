@@ -3,7 +3,7 @@ use anchor_spl::token::{self, Mint, TokenAccount};
 
 use crate::{
     constants::*, utils::*, AuctionHouseError, EditionDistributor, PriceFunction,
-    PriceFunctionType, EDITION_DISTRIBUTOR_SIZE,
+    PriceFunctionType, EDITION_DISTRIBUTOR_SIZE, EDITION_DISTRIBUTOR_VERSION,
 };
 
 #[derive(Accounts)]
@@ -111,6 +111,16 @@ pub fn handle_create_edition_distributor<'info>(
     edition_distributor.limit_per_address = 0;
     edition_distributor.treasury_mint = treasury_mint.key();
     edition_distributor.allowlist_number_sold = 0;
+    edition_distributor.max_editions_to_sell = None;
+    edition_distributor.is_supply_locked = false;
+    edition_distributor.reserved_number_minted = 0;
+    edition_distributor.bot_tax_amount = None;
+    edition_distributor.bot_tax_recipient = None;
+    edition_distributor.total_number_sold = 0;
+    edition_distributor.total_gross_revenue = 0;
+    edition_distributor.total_platform_fees = 0;
+    edition_distributor.total_creator_proceeds = 0;
+    edition_distributor.version = EDITION_DISTRIBUTOR_VERSION;
 
     Ok(())
 }
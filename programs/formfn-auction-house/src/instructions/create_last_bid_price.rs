@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
-use crate::{constants::*, utils::*, AuctionHouse, LastBidPrice, LAST_BID_PRICE_SIZE};
+use crate::{
+    constants::*, utils::*, AuctionHouse, LastBidPrice, LAST_BID_PRICE_SIZE, LAST_BID_PRICE_VERSION,
+};
 
 #[derive(Accounts)]
 pub struct CreateLastBidPrice<'info> {
@@ -38,6 +40,7 @@ pub fn handle_create_last_bid_price<'info>(
     let auction_house = &ctx.accounts.auction_house;
     let token_mint = &ctx.accounts.token_mint;
     let last_bid_price = &mut ctx.accounts.last_bid_price;
+    let wallet = &ctx.accounts.wallet;
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
     assert_valid_last_bid_price(
@@ -50,7 +53,12 @@ pub fn handle_create_last_bid_price<'info>(
     last_bid_price.bidder = Some(ZERO_PUBKEY);
     last_bid_price.has_been_sold = 0;
     last_bid_price.tick_size_constant_in_lamports = 0;
+    last_bid_price.tick_size_percent = 0;
+    last_bid_price.tick_size_min_in_lamports = 0;
+    last_bid_price.tick_size_max_in_lamports = 0;
     last_bid_price.has_campaign_escrow_treasury = false;
+    last_bid_price.rent_payer = Some(wallet.key());
+    last_bid_price.version = LAST_BID_PRICE_VERSION;
 
     Ok(())
 }
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseRole, LastBidPrice, LastBidPriceAuditLog,
+    LAST_BID_PRICE_AUDIT_LOG_SIZE, LAST_BID_PRICE_AUDIT_LOG_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct CreateLastBidPriceAuditLog<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    authority: Signer<'info>,
+    token_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(mut)]
+    last_bid_price: Account<'info, LastBidPrice>,
+    #[account(
+        init,
+        payer = payer,
+        space = LAST_BID_PRICE_AUDIT_LOG_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+            LAST_BID_PRICE_AUDIT_LOG.as_bytes()
+        ],
+        bump
+    )]
+    last_bid_price_audit_log: Account<'info, LastBidPriceAuditLog>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Creates the append-only audit log of admin overrides for a mint's LastBidPrice. From then
+ * on set_last_bid_price, set_previous_bidder and set_has_been_sold must pass the log in
+ * remaining_accounts. There is no instruction to close the log.
+ */
+pub fn handle_create_last_bid_price_audit_log<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateLastBidPriceAuditLog<'info>>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let token_mint = &ctx.accounts.token_mint;
+    let last_bid_price = &mut ctx.accounts.last_bid_price;
+    let last_bid_price_audit_log = &mut ctx.accounts.last_bid_price_audit_log;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::ListingModerator,
+    )?;
+    assert_valid_last_bid_price(
+        &last_bid_price.to_account_info(),
+        ctx.program_id,
+        &token_mint.key(),
+    )?;
+
+    last_bid_price_audit_log.auction_house = auction_house.key();
+    last_bid_price_audit_log.mint = token_mint.key();
+    last_bid_price_audit_log.bump = *ctx.bumps.get("last_bid_price_audit_log").unwrap();
+    last_bid_price_audit_log.version = LAST_BID_PRICE_AUDIT_LOG_VERSION;
+    last_bid_price_audit_log.entries = vec![];
+
+    last_bid_price.has_audit_log = true;
+
+    Ok(())
+}
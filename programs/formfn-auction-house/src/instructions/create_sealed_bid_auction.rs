@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, TokenAccount};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, SealedBidAuction,
+    SEALED_BID_AUCTION_SIZE, SEALED_BID_AUCTION_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct CreateSealedBidAuction<'info> {
+    #[account(mut)]
+    seller: Signer<'info>,
+    token_mint: Account<'info, Mint>,
+    #[account(
+        constraint = token_account.owner == seller.key(),
+        constraint = token_account.mint == token_mint.key(),
+        owner = token::ID
+    )]
+    token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init,
+        seeds = [
+            SEALED_BID_AUCTION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        payer = seller,
+        space = SEALED_BID_AUCTION_SIZE,
+        bump
+    )]
+    sealed_bid_auction: Account<'info, SealedBidAuction>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Creates a sealed bid auction for an NFT. The seller then lists the NFT with a trade state
+ * of sale type SealedBidAuction, at reserve_price. Bids are placed with commit_sealed_bid
+ * and revealed with reveal_sealed_bid, after which the winner settles the sale with
+ * execute_sale_v2 and everyone else is refunded with refund_sealed_bid.
+ */
+pub fn handle_create_sealed_bid_auction<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSealedBidAuction<'info>>,
+    reserve_price: u64,
+    // Unix time (seconds since epoch)
+    commit_end_time: i64,
+    // Unix time (seconds since epoch)
+    reveal_end_time: i64,
+    is_second_price: bool,
+) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let token_mint = &ctx.accounts.token_mint;
+    let token_account = &ctx.accounts.token_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let sealed_bid_auction = &mut ctx.accounts.sealed_bid_auction;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
+
+    if token_account.amount < 1 {
+        return Err(AuctionHouseError::InvalidTokenAccountAmount.into());
+    }
+
+    let clock = Clock::get()?;
+    if commit_end_time <= clock.unix_timestamp || reveal_end_time <= commit_end_time {
+        return Err(AuctionHouseError::InvalidSealedBidAuctionTimes.into());
+    }
+
+    sealed_bid_auction.auction_house = auction_house.key();
+    sealed_bid_auction.token_mint = token_mint.key();
+    sealed_bid_auction.seller = seller.key();
+    sealed_bid_auction.reserve_price = reserve_price;
+    sealed_bid_auction.commit_end_time = commit_end_time;
+    sealed_bid_auction.reveal_end_time = reveal_end_time;
+    sealed_bid_auction.is_second_price = is_second_price;
+    sealed_bid_auction.bump = *ctx.bumps.get("sealed_bid_auction").unwrap();
+    sealed_bid_auction.version = SEALED_BID_AUCTION_VERSION;
+
+    Ok(())
+}
@@ -1,21 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, TokenAccount};
-use std::convert::TryFrom;
 
 use crate::{
-    constants::*, utils::*, AuctionHouse, AuctionHouseError, TRADE_STATE_SIZE, TRADE_STATE_SIZE_U16,
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, TradeState, TRADE_STATE_SIZE,
+    TRADE_STATE_VERSION,
 };
 
 #[derive(Accounts)]
 #[instruction(trade_state_bump: u8, buyer_price: u64, token_size: u64)]
 pub struct CreateTradeState<'info> {
     /// CHECK: No need to deserialize.
-    authority: UncheckedAccount<'info>,
+    pub authority: UncheckedAccount<'info>,
     /// CHECK: No need to deserialize.
-    wallet: UncheckedAccount<'info>,
-    token_mint: Account<'info, Mint>,
+    pub wallet: UncheckedAccount<'info>,
+    pub token_mint: Account<'info, Mint>,
     #[account(owner=token::ID)]
-    token_account: Account<'info, TokenAccount>,
+    pub token_account: Account<'info, TokenAccount>,
     #[account(
         has_one = authority,
         has_one = auction_house_fee_account,
@@ -26,7 +26,7 @@ pub struct CreateTradeState<'info> {
         ],
         bump = auction_house.bump,
     )]
-    auction_house: Account<'info, AuctionHouse>,
+    pub auction_house: Account<'info, AuctionHouse>,
     /// CHECK: No need to deserialize.
     #[account(
         mut,
@@ -37,7 +37,7 @@ pub struct CreateTradeState<'info> {
         ],
         bump = auction_house.fee_payer_bump
     )]
-    auction_house_fee_account: UncheckedAccount<'info>,
+    pub auction_house_fee_account: UncheckedAccount<'info>,
     /// CHECK: No need to deserialize.
     #[account(
         mut,
@@ -53,36 +53,55 @@ pub struct CreateTradeState<'info> {
         ],
         bump = trade_state_bump
     )]
-    trade_state: UncheckedAccount<'info>,
-    system_program: Program<'info, System>,
-    rent: Sysvar<'info, Rent>,
+    pub trade_state: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
+/**
+ * trade_state_size is no longer used, since trade states are always TradeState accounts. It is
+ * kept so existing clients keep working.
+ */
 pub fn handle_create_trade_state<'info>(
     ctx: Context<'_, '_, '_, 'info, CreateTradeState<'info>>,
     trade_state_bump: u8,
     price: u64,
     token_size: u64,
     sale_type: u8,
-    trade_state_size: Option<u16>,
+    _trade_state_size: Option<u16>,
 ) -> Result<()> {
-    let wallet = &ctx.accounts.wallet;
-    let authority = &ctx.accounts.authority;
-    let token_mint = &ctx.accounts.token_mint;
-    let token_account = &ctx.accounts.token_account;
-    let auction_house = &ctx.accounts.auction_house;
-    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
-    let trade_state = &mut ctx.accounts.trade_state;
-    let system_program = &ctx.accounts.system_program;
-    let rent = &ctx.accounts.rent;
+    process_create_trade_state(
+        ctx.accounts,
+        ctx.program_id,
+        trade_state_bump,
+        price,
+        token_size,
+        sale_type,
+        None,
+    )
+}
 
-    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+pub fn process_create_trade_state<'info>(
+    accounts: &CreateTradeState<'info>,
+    program_id: &Pubkey,
+    trade_state_bump: u8,
+    price: u64,
+    token_size: u64,
+    sale_type: u8,
+    // Unix time (seconds since epoch)
+    expiry: Option<i64>,
+) -> Result<()> {
+    let wallet = &accounts.wallet;
+    let authority = &accounts.authority;
+    let token_mint = &accounts.token_mint;
+    let token_account = &accounts.token_account;
+    let auction_house = &accounts.auction_house;
+    let auction_house_fee_account = &accounts.auction_house_fee_account;
+    let trade_state = &accounts.trade_state;
+    let system_program = &accounts.system_program;
+    let rent = &accounts.rent;
 
-    let size = usize::try_from(trade_state_size.unwrap_or(TRADE_STATE_SIZE_U16));
-    let trade_state_allocation_size = match size {
-        Ok(size) => size,
-        Err(_err) => TRADE_STATE_SIZE,
-    };
+    assert_valid_auction_house(program_id, &auction_house.key())?;
 
     assert_keys_equal(token_mint.key(), token_account.mint)?;
 
@@ -92,6 +111,7 @@ pub fn handle_create_trade_state<'info>(
     if token_account.amount < 1 {
         return Err(AuctionHouseError::InvalidTokenAccountAmount.into());
     }
+    assert_valid_trade_state_sale_type(sale_type)?;
     let ts_info = trade_state.to_account_info();
     if !ts_info.data_is_empty() {
         return Err(AuctionHouseError::TradeStateAlreadyInitialized.into());
@@ -125,21 +145,32 @@ pub fn handle_create_trade_state<'info>(
         &[trade_state_bump],
     ];
     create_or_allocate_account_raw(
-        *ctx.program_id,
+        *program_id,
         &ts_info,
         &rent.to_account_info(),
         &system_program,
         &fee_payer,
-        trade_state_allocation_size,
+        TRADE_STATE_SIZE,
         fee_seeds,
         &ts_seeds,
     )?;
 
-    let data = &mut ts_info.data.borrow_mut();
-    data[0] = trade_state_bump;
-    if trade_state_allocation_size > (1 as usize) {
-        data[1] = sale_type;
-    }
+    let (seller, buyer) = get_trade_state_parties(wallet_key, token_account.owner);
+    write_trade_state(
+        &ts_info,
+        &TradeState {
+            bump: trade_state_bump,
+            version: TRADE_STATE_VERSION,
+            sale_type,
+            seller,
+            buyer,
+            token_mint: token_mint.key(),
+            price,
+            token_size,
+            created_at: Clock::get()?.unix_timestamp,
+            expiry,
+        },
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{process_create_trade_state, AuctionHouseError, CreateTradeState};
+
+/**
+ * Same as create_trade_state, but the trade state can no longer be executed after expiry.
+ */
+pub fn handle_create_trade_state_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateTradeState<'info>>,
+    trade_state_bump: u8,
+    price: u64,
+    token_size: u64,
+    sale_type: u8,
+    // Unix time (seconds since epoch)
+    expiry: Option<i64>,
+) -> Result<()> {
+    if let Some(expiry_val) = expiry {
+        if expiry_val <= Clock::get()?.unix_timestamp {
+            return Err(AuctionHouseError::InvalidTradeStateExpiry.into());
+        }
+    }
+
+    process_create_trade_state(
+        ctx.accounts,
+        ctx.program_id,
+        trade_state_bump,
+        price,
+        token_size,
+        sale_type,
+        expiry,
+    )
+}
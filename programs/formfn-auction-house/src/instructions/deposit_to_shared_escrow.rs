@@ -0,0 +1,78 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, SharedEscrow, SHARED_ESCROW_SIZE,
+    SHARED_ESCROW_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct DepositToSharedEscrow<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = SHARED_ESCROW_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            SHARED_ESCROW.as_bytes()
+        ],
+        bump
+    )]
+    shared_escrow: Account<'info, SharedEscrow>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Deposits funds into the wallet's shared escrow, creating it if needed. Unlike escrow
+ * payment accounts, a shared escrow is not tied to a mint, so the same deposit can back
+ * bids on many auctions (see buy_v2_with_shared_escrow).
+ */
+pub fn handle_deposit_to_shared_escrow(
+    ctx: Context<DepositToSharedEscrow>,
+    amount: u64,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let auction_house = &ctx.accounts.auction_house;
+    let shared_escrow = &mut ctx.accounts.shared_escrow;
+    let system_program = &ctx.accounts.system_program;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
+
+    if auction_house.treasury_mint != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::SharedEscrowRequiresNativeMint.into());
+    }
+
+    if shared_escrow.version == 0 {
+        shared_escrow.wallet = wallet.key();
+        shared_escrow.auction_house = auction_house.key();
+        shared_escrow.bump = *ctx.bumps.get("shared_escrow").unwrap();
+        shared_escrow.version = SHARED_ESCROW_VERSION;
+    }
+
+    invoke(
+        &system_instruction::transfer(&wallet.key(), &shared_escrow.key(), amount),
+        &[
+            wallet.to_account_info(),
+            shared_escrow.to_account_info(),
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::{Mint, Token};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, TreasurySplit};
+
+#[derive(Accounts)]
+pub struct DistributeTreasury<'info> {
+    treasury_mint: Account<'info, Mint>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump = auction_house.treasury_bump
+    )]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        has_one = treasury_mint,
+        has_one = auction_house_treasury,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            treasury_mint.key().as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY_SPLIT.as_bytes()
+        ],
+        bump = treasury_split.bump,
+    )]
+    treasury_split: Account<'info, TreasurySplit>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Permissionlessly splits the treasury balance between the recipients of the auction house's
+ * TreasurySplit. Each recipient's wallet (for native treasuries) or associated token account
+ * (for SPL treasuries) must be passed in remaining_accounts. Native treasuries keep their
+ * rent exempt minimum.
+ */
+pub fn handle_distribute_treasury<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeTreasury<'info>>,
+) -> Result<()> {
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let auction_house = &ctx.accounts.auction_house;
+    let treasury_split = &ctx.accounts.treasury_split;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
+
+    if treasury_split.recipients.is_empty() {
+        return Err(AuctionHouseError::TreasurySplitNotSet.into());
+    }
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+    let treasury_balance = if is_native {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(auction_house_treasury.data_len());
+        auction_house_treasury
+            .lamports()
+            .saturating_sub(rent_exempt_minimum)
+    } else {
+        assert_initialized::<spl_token::state::Account>(auction_house_treasury)?.amount
+    };
+    if treasury_balance == 0 {
+        return Err(AuctionHouseError::NothingToDistribute.into());
+    }
+
+    let auction_house_seeds = [
+        PREFIX.as_bytes(),
+        auction_house.creator.as_ref(),
+        auction_house.treasury_mint.as_ref(),
+        &[auction_house.bump],
+    ];
+
+    let ah_key = auction_house.key();
+    let auction_house_treasury_seeds = [
+        PREFIX.as_bytes(),
+        ah_key.as_ref(),
+        TREASURY.as_bytes(),
+        &[auction_house.treasury_bump],
+    ];
+
+    let recipient_amounts =
+        get_revenue_split_amounts(&treasury_split.recipients, treasury_balance)?;
+    for (recipient, amount) in recipient_amounts {
+        if amount == 0 {
+            continue;
+        }
+
+        if !is_native {
+            let recipient_ata = get_associated_token_address(&recipient, &treasury_mint.key());
+            let recipient_token_account =
+                find_revenue_split_recipient_account(ctx.remaining_accounts, &recipient_ata)?;
+            assert_is_ata(recipient_token_account, &recipient, &treasury_mint.key())?;
+            invoke_signed(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    &auction_house_treasury.key(),
+                    recipient_token_account.key,
+                    &auction_house.key(),
+                    &[],
+                    amount,
+                )?,
+                &[
+                    auction_house_treasury.to_account_info(),
+                    recipient_token_account.clone(),
+                    token_program.to_account_info(),
+                    auction_house.to_account_info(),
+                ],
+                &[&auction_house_seeds],
+            )?;
+        } else {
+            let recipient_wallet =
+                find_revenue_split_recipient_account(ctx.remaining_accounts, &recipient)?;
+            invoke_signed(
+                &system_instruction::transfer(
+                    &auction_house_treasury.key(),
+                    recipient_wallet.key,
+                    amount,
+                ),
+                &[
+                    auction_house_treasury.to_account_info(),
+                    recipient_wallet.clone(),
+                    system_program.to_account_info(),
+                ],
+                &[&auction_house_treasury_seeds],
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -30,7 +30,8 @@ pub struct ExecuteSaleV2<'info> {
     // 4th account, cannot mark these as real Accounts or else we blow stack size limit
     /// CHECK: No need to deserialize.
     token_mint: UncheckedAccount<'info>,
-    /// CHECK: No need to deserialize.
+    /// CHECK: No need to deserialize. Only needs to be writable for the primary sale to be
+    /// recorded in it, see update_primary_sale_happened_via_token.
     metadata: UncheckedAccount<'info>,
     /// CHECK: No need to deserialize.
     treasury_mint: UncheckedAccount<'info>,
@@ -103,7 +104,7 @@ pub struct ExecuteSaleV2<'info> {
             &buyer_price.to_le_bytes(),
             &token_size.to_le_bytes()
         ],
-        bump = buyer_trade_state.to_account_info().data.borrow()[0]
+        bump = get_trade_state(&buyer_trade_state.to_account_info())?.bump
     )]
     buyer_trade_state: UncheckedAccount<'info>,
     /// CHECK: No need to deserialize.
@@ -119,7 +120,7 @@ pub struct ExecuteSaleV2<'info> {
             &seller_price.to_le_bytes(),
             &token_size.to_le_bytes()
         ],
-        bump = seller_trade_state.to_account_info().data.borrow()[0]
+        bump = get_trade_state(&seller_trade_state.to_account_info())?.bump
     )]
     seller_trade_state: UncheckedAccount<'info>,
     /// CHECK: No need to deserialize.
@@ -195,23 +196,17 @@ pub fn handle_execute_sale_v2<'info>(
     let rent = &ctx.accounts.rent;
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
     assert_valid_last_bid_price(
         &last_bid_price.to_account_info(),
         ctx.program_id,
         &token_mint.key(),
     )?;
 
-    let metadata_clone = metadata.to_account_info();
     let escrow_clone = escrow_payment_account.to_account_info();
-    let auction_house_clone = auction_house.to_account_info();
-    let ata_clone = ata_program.to_account_info();
     let token_clone = token_program.to_account_info();
     let sys_clone = system_program.to_account_info();
-    let rent_clone = rent.to_account_info();
-    let treasury_clone = auction_house_treasury.to_account_info();
     let authority_clone = authority.to_account_info();
-    let buyer_receipt_clone = buyer_receipt_token_account.to_account_info();
-    let token_account_clone = token_account.to_account_info();
     let seller_trade_state_clone = seller_trade_state.to_account_info();
     let buyer_trade_state_clone = buyer_trade_state.to_account_info();
 
@@ -225,19 +220,28 @@ pub fn handle_execute_sale_v2<'info>(
         return Err(AuctionHouseError::BothPartiesNeedToAgreeToSale.into());
     }
 
-    if seller_trade_state_clone.data.borrow()[0] == 0
-        || buyer_trade_state_clone.data.borrow()[0] == 0
-    {
+    let seller_trade_state_data = get_trade_state(&seller_trade_state_clone)?;
+    let buyer_trade_state_data = get_trade_state(&buyer_trade_state_clone)?;
+
+    if seller_trade_state_data.bump == 0 || buyer_trade_state_data.bump == 0 {
         return Err(AuctionHouseError::CanOnlyExecuteSaleOnce.into());
     }
 
+    if last_bid_price.bidder_uses_shared_escrow {
+        return Err(AuctionHouseError::SharedEscrowReservationNotSettled.into());
+    }
+
     let seller_or_authority_signed = seller.is_signer || authority_clone.is_signer;
 
-    let seller_sale_type = get_trade_state_sale_type(&seller_trade_state.to_account_info());
-    let buyer_sale_type = get_trade_state_sale_type(&buyer_trade_state.to_account_info());
+    let seller_sale_type = assert_valid_trade_state_sale_type(seller_trade_state_data.sale_type)?;
+    let buyer_sale_type = assert_valid_trade_state_sale_type(buyer_trade_state_data.sale_type)?;
     msg!("seller_sale_type = {}", seller_sale_type);
     msg!("buyer_sale_type = {}", buyer_sale_type);
 
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    assert_trade_state_not_expired(&seller_trade_state_data, unix_timestamp)?;
+    assert_trade_state_not_expired(&buyer_trade_state_data, unix_timestamp)?;
+
     // Make sure seller and buyer sale types match except if seller is
     // accepting offer
     if buyer_sale_type != TradeStateSaleType::Offer && seller_sale_type != buyer_sale_type {
@@ -250,17 +254,37 @@ pub fn handle_execute_sale_v2<'info>(
         return Err(AuctionHouseError::CannotAcceptOfferWhileOnAuction.into());
     }
 
+    // The price the sale is paid at. For proxy bids this is the visible price, and the rest of
+    // the max bid (buyer_price) stays in the buyer's escrow payment account.
+    let mut sale_price = buyer_price;
+    // Charged to the buyer on top of sale_price for auctions, see
+    // set_buyer_premium_basis_points.
+    let mut buyer_premium = 0;
+
     match buyer_sale_type {
         TradeStateSaleType::Auction => {
-            // Only let seller or auction house authority execute sale for auctions and offers
+            // Only let seller or auction house authority execute sale for auctions and offers.
+            // Buyers settle at the buy now price with buy_now instead.
             if !seller_or_authority_signed {
                 return Err(AuctionHouseError::SellerOrAuctionHouseMustSign.into());
             }
 
-            // Make sure buyer_price is right
-            if buyer_price != last_bid_price.price {
+            // Make sure buyer_price is right, which is the max bid for proxy bids
+            if buyer_price != get_max_bid(last_bid_price) {
                 return Err(AuctionHouseError::MismatchedPrices.into());
             }
+            if last_bid_price.proxy_max_bid > 0 {
+                if last_bid_price.bidder != Some(buyer.key()) {
+                    return Err(AuctionHouseError::PreviousBidderIncorrect.into());
+                }
+                sale_price = last_bid_price.price;
+                if sale_price < seller_price {
+                    return Err(AuctionHouseError::MismatchedPrices.into());
+                }
+            }
+            // The premium was escrowed with the bid, at the rate in effect when it was placed
+            buyer_premium =
+                get_buyer_premium(sale_price, last_bid_price.buyer_premium_basis_points);
         }
         TradeStateSaleType::Offer => {
             // Only let seller or auction house authority execute sale for auctions and offers
@@ -274,15 +298,167 @@ pub fn handle_execute_sale_v2<'info>(
                 return Err(AuctionHouseError::BuyerOrAuctionHouseMustSign.into());
             }
         }
+        TradeStateSaleType::SealedBidAuction => {
+            // The winner is determined on-chain, so the buyer can also execute the sale
+            if !seller_or_authority_signed && !buyer.is_signer {
+                return Err(AuctionHouseError::SellerOrAuctionHouseMustSign.into());
+            }
+
+            // The SealedBidAuction account is passed after the creators in remaining_accounts
+            let mut sealed_bid_auction = get_sealed_bid_auction_from_remaining_accounts(
+                ctx.remaining_accounts,
+                ctx.program_id,
+                &auction_house.key(),
+                &token_mint.key(),
+            )?;
+            assert_keys_equal(sealed_bid_auction.seller, seller.key())?;
+            assert_sealed_bid_auction_winner(
+                &sealed_bid_auction,
+                &buyer.key(),
+                buyer_price,
+                unix_timestamp,
+            )?;
+            // The winner's deposit is held in their sealed bid escrow, which is passed in
+            // remaining_accounts along with their SealedBid
+            fund_escrow_payment_account_from_sealed_bid(
+                ctx.remaining_accounts,
+                ctx.program_id,
+                auction_house,
+                &sealed_bid_auction.key(),
+                &buyer.key(),
+                &escrow_clone,
+                &token_clone,
+                &sys_clone,
+                buyer_price,
+                is_native,
+            )?;
+            sealed_bid_auction.is_settled = true;
+            sealed_bid_auction.exit(ctx.program_id)?;
+        }
     }
 
+    settle_sale(
+        &SaleAccounts {
+            buyer: buyer.to_account_info(),
+            seller: seller.to_account_info(),
+            token_account,
+            token_mint: token_mint.to_account_info(),
+            metadata: metadata.to_account_info(),
+            treasury_mint: treasury_mint.to_account_info(),
+            seller_payment_receipt_account: seller_payment_receipt_account.to_account_info(),
+            buyer_receipt_token_account: buyer_receipt_token_account.to_account_info(),
+            escrow_payment_account: escrow_clone,
+            authority,
+            auction_house,
+            auction_house_fee_account: auction_house_fee_account.to_account_info(),
+            auction_house_treasury: auction_house_treasury.to_account_info(),
+            buyer_trade_state: Some(buyer_trade_state_clone),
+            seller_trade_state: seller_trade_state_clone,
+            free_trade_state: free_trade_state.to_account_info(),
+            token_program: token_clone,
+            system_program: sys_clone,
+            ata_program: ata_program.to_account_info(),
+            program_as_signer: program_as_signer.to_account_info(),
+            rent: rent.to_account_info(),
+            master_edition: master_edition.to_account_info(),
+            metaplex_token_metadata_program: metaplex_token_metadata_program.to_account_info(),
+        },
+        last_bid_price,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        escrow_payment_bump,
+        program_as_signer_bump,
+        sale_price,
+        buyer_premium,
+        token_size,
+        unix_timestamp,
+    )
+}
+
+/**
+ * The accounts a sale is settled with, see settle_sale.
+ */
+pub struct SaleAccounts<'a, 'info> {
+    pub buyer: AccountInfo<'info>,
+    pub seller: AccountInfo<'info>,
+    pub token_account: &'a Account<'info, TokenAccount>,
+    pub token_mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub treasury_mint: AccountInfo<'info>,
+    pub seller_payment_receipt_account: AccountInfo<'info>,
+    pub buyer_receipt_token_account: AccountInfo<'info>,
+    pub escrow_payment_account: AccountInfo<'info>,
+    pub authority: &'a UncheckedAccount<'info>,
+    pub auction_house: &'a Account<'info, AuctionHouse>,
+    pub auction_house_fee_account: AccountInfo<'info>,
+    pub auction_house_treasury: AccountInfo<'info>,
+    // None for buy now purchases, which the buyer signs instead of placing a bid.
+    pub buyer_trade_state: Option<AccountInfo<'info>>,
+    pub seller_trade_state: AccountInfo<'info>,
+    pub free_trade_state: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub ata_program: AccountInfo<'info>,
+    pub program_as_signer: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub master_edition: AccountInfo<'info>,
+    pub metaplex_token_metadata_program: AccountInfo<'info>,
+}
+
+/**
+ * Pays for the token out of the buyer's escrow payment account, transfers the token to the
+ * buyer, closes the trade states and resets the LastBidPrice. The caller validates the sale
+ * and works out sale_price and buyer_premium, which must already be in the escrow payment
+ * account. Shared by execute_sale_v2 and buy_now.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn settle_sale<'info>(
+    accounts: &SaleAccounts<'_, 'info>,
+    last_bid_price: &mut LastBidPrice,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    escrow_payment_bump: u8,
+    program_as_signer_bump: u8,
+    sale_price: u64,
+    buyer_premium: u64,
+    token_size: u64,
+    unix_timestamp: i64,
+) -> Result<()> {
+    let SaleAccounts {
+        buyer,
+        seller,
+        token_account,
+        token_mint,
+        metadata: metadata_info,
+        treasury_mint,
+        seller_payment_receipt_account,
+        buyer_receipt_token_account,
+        escrow_payment_account,
+        authority,
+        auction_house,
+        auction_house_fee_account,
+        auction_house_treasury,
+        buyer_trade_state,
+        seller_trade_state,
+        free_trade_state,
+        token_program,
+        system_program,
+        ata_program,
+        program_as_signer,
+        rent,
+        master_edition,
+        metaplex_token_metadata_program,
+    } = accounts;
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
     // Can't check this with Anchor macros because token_account is boxed
     assert_keys_equal(*token_account.to_account_info().owner, token_program.key())?;
-    let token_account_mint = get_mint_from_token_account(&token_account_clone)?;
-    let token_account_owner = get_owner_from_token_account(&token_account_clone)?;
+    let token_account_mint = get_mint_from_token_account(&token_account.to_account_info())?;
+    let token_account_owner = get_owner_from_token_account(&token_account.to_account_info())?;
     assert_keys_equal(token_mint.key(), token_account_mint)?;
     assert_token_account_owner(token_account_owner, seller.key())?;
-    let delegate = get_delegate_from_token_account(&token_account_clone)?;
+    let delegate = get_delegate_from_token_account(&token_account.to_account_info())?;
     if let Some(d) = delegate {
         assert_keys_equal(program_as_signer.key(), d)?;
     } else if !seller.is_signer {
@@ -307,11 +483,10 @@ pub fn handle_execute_sale_v2<'info>(
         auction_house_fee_account.to_account_info(),
         &seeds,
     )?;
-    let fee_payer_clone = fee_payer.to_account_info();
 
     assert_derivation(
         &mpl_token_metadata::id(),
-        &metadata.to_account_info(),
+        metadata_info,
         &[
             mpl_token_metadata::state::PREFIX.as_bytes(),
             mpl_token_metadata::id().as_ref(),
@@ -319,7 +494,7 @@ pub fn handle_execute_sale_v2<'info>(
         ],
     )?;
 
-    if metadata.data_is_empty() {
+    if metadata_info.data_is_empty() {
         return Err(AuctionHouseError::MetadataDoesntExist.into());
     }
 
@@ -332,6 +507,114 @@ pub fn handle_execute_sale_v2<'info>(
             // 4. Call create_campaign_treasury_manager_deposit here and transfer
             //    all sale proceeds to the treasury manager.
         }
+        false if auction_house.claimable_balances_enabled => {
+            // Creators and the seller are credited in their ClaimableBalance accounts, which
+            // are passed in remaining_accounts instead of the creators, and claim them later.
+            // For SPL auction houses the ClaimableBalances' token accounts are also passed.
+            let auction_house_key = auction_house.key();
+            let wallet_key = buyer.key();
+            let token_mint_key = token_mint.key();
+            let escrow_signer_seeds = [
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                wallet_key.as_ref(),
+                token_mint_key.as_ref(),
+                &[escrow_payment_bump],
+            ];
+            let ah_seeds = [
+                PREFIX.as_bytes(),
+                auction_house.creator.as_ref(),
+                auction_house.treasury_mint.as_ref(),
+                &[auction_house.bump],
+            ];
+            let signer_seeds = if is_native {
+                escrow_signer_seeds.to_vec()
+            } else {
+                ah_seeds.to_vec()
+            };
+
+            let metadata = Metadata::from_account_info(metadata_info)?;
+            let (mut claimable_amounts, buyer_leftover_after_royalties) =
+                get_creator_fee_amounts(&metadata, sale_price)?;
+            let has_been_sold = get_has_been_sold(&metadata, Some(last_bid_price));
+            let fee_override = get_fee_override_from_remaining_accounts(
+                remaining_accounts,
+                program_id,
+                &auction_house_key,
+                &token_mint_key,
+                &metadata,
+                unix_timestamp,
+            )?;
+            let auction_house_fee_paid = pay_auction_house_fees(
+                &auction_house,
+                auction_house_treasury,
+                escrow_payment_account,
+                token_program,
+                system_program,
+                &signer_seeds,
+                sale_price,
+                is_native,
+                has_been_sold,
+                fee_override.as_ref(),
+            )?;
+            pay_buyer_premium(
+                &auction_house,
+                auction_house_treasury,
+                escrow_payment_account,
+                token_program,
+                system_program,
+                &signer_seeds,
+                buyer_premium,
+                is_native,
+            )?;
+
+            let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
+                .checked_sub(auction_house_fee_paid)
+                .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+            if last_bid_price.has_revenue_split && !has_been_sold {
+                let revenue_split = get_revenue_split_from_remaining_accounts(
+                    remaining_accounts,
+                    program_id,
+                    &auction_house_key,
+                    &token_mint_key,
+                )?;
+                claimable_amounts.extend(get_revenue_split_amounts(
+                    &revenue_split.recipients,
+                    buyer_leftover_after_royalties_and_house_fee,
+                )?);
+            } else if should_split_primary_sale(&metadata, &seller.key(), has_been_sold) {
+                claimable_amounts.extend(get_primary_sale_amounts(
+                    &metadata,
+                    buyer_leftover_after_royalties_and_house_fee,
+                )?);
+            } else {
+                claimable_amounts.push((
+                    seller.key(),
+                    buyer_leftover_after_royalties_and_house_fee,
+                ));
+            }
+
+            for (recipient, amount) in claimable_amounts {
+                credit_claimable_balance(
+                    remaining_accounts,
+                    program_id,
+                    &auction_house,
+                    &recipient,
+                    escrow_payment_account,
+                    &fee_payer,
+                    treasury_mint,
+                    ata_program,
+                    token_program,
+                    system_program,
+                    rent,
+                    &signer_seeds,
+                    &fee_payer_seeds,
+                    amount,
+                    is_native,
+                )?;
+            }
+        }
         false => {
             let auction_house_key = auction_house.key();
             let wallet_key = buyer.key();
@@ -360,34 +643,53 @@ pub fn handle_execute_sale_v2<'info>(
             };
 
             let buyer_leftover_after_royalties = pay_creator_fees(
-                &mut ctx.remaining_accounts.iter(),
-                &metadata_clone,
-                &escrow_clone,
-                &auction_house_clone,
-                &fee_payer_clone,
+                &mut remaining_accounts.iter(),
+                metadata_info,
+                escrow_payment_account,
+                &auction_house.to_account_info(),
+                &fee_payer,
                 treasury_mint,
-                &ata_clone,
-                &token_clone,
-                &sys_clone,
-                &rent_clone,
+                ata_program,
+                token_program,
+                system_program,
+                rent,
                 &signer_seeds_for_royalties,
                 &fee_payer_seeds,
-                buyer_price,
+                sale_price,
                 is_native,
             )?;
 
-            let metadata = Metadata::from_account_info(&metadata_clone)?;
+            let metadata = Metadata::from_account_info(metadata_info)?;
             let has_been_sold = get_has_been_sold(&metadata, Some(last_bid_price));
+            let fee_override = get_fee_override_from_remaining_accounts(
+                remaining_accounts,
+                program_id,
+                &auction_house_key,
+                &token_mint_key,
+                &metadata,
+                unix_timestamp,
+            )?;
             let auction_house_fee_paid = pay_auction_house_fees(
                 &auction_house,
-                &treasury_clone,
-                &escrow_clone,
-                &token_clone,
-                &sys_clone,
+                auction_house_treasury,
+                escrow_payment_account,
+                token_program,
+                system_program,
                 &signer_seeds_for_royalties,
-                buyer_price,
+                sale_price,
                 is_native,
                 has_been_sold,
+                fee_override.as_ref(),
+            )?;
+            pay_buyer_premium(
+                &auction_house,
+                auction_house_treasury,
+                escrow_payment_account,
+                token_program,
+                system_program,
+                &signer_seeds_for_royalties,
+                buyer_premium,
+                is_native,
             )?;
 
             let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
@@ -397,29 +699,52 @@ pub fn handle_execute_sale_v2<'info>(
             let should_split_primary =
                 should_split_primary_sale(&metadata, &seller.key(), has_been_sold);
 
-            if should_split_primary {
+            if last_bid_price.has_revenue_split && !has_been_sold {
+                let revenue_split = get_revenue_split_from_remaining_accounts(
+                    remaining_accounts,
+                    program_id,
+                    &auction_house_key,
+                    &token_mint_key,
+                )?;
+                pay_revenue_split(
+                    remaining_accounts,
+                    &revenue_split,
+                    escrow_payment_account,
+                    &auction_house.to_account_info(),
+                    &fee_payer,
+                    treasury_mint,
+                    ata_program,
+                    token_program,
+                    system_program,
+                    rent,
+                    &signer_seeds_for_royalties,
+                    &fee_payer_seeds,
+                    buyer_leftover_after_royalties_and_house_fee,
+                    is_native,
+                )?;
+            } else if should_split_primary {
                 if !is_native {
                     split_primary_sale_between_creators_non_native(
-                        &mut ctx.remaining_accounts.iter(),
-                        &metadata_clone,
-                        &escrow_clone,
-                        &auction_house_clone,
-                        &fee_payer_clone,
+                        &mut remaining_accounts.iter(),
+                        metadata_info,
+                        escrow_payment_account,
+                        &auction_house.to_account_info(),
+                        &fee_payer,
                         treasury_mint,
-                        &ata_clone,
-                        &token_clone,
-                        &sys_clone,
-                        &rent_clone,
+                        ata_program,
+                        token_program,
+                        system_program,
+                        rent,
                         &signer_seeds_for_royalties,
                         &fee_payer_seeds,
                         buyer_leftover_after_royalties_and_house_fee,
                     )?;
                 } else {
                     split_primary_sale_between_creators_native(
-                        &mut ctx.remaining_accounts.iter(),
-                        &metadata_clone,
-                        &escrow_clone,
-                        &sys_clone,
+                        &mut remaining_accounts.iter(),
+                        metadata_info,
+                        escrow_payment_account,
+                        system_program,
                         &signer_seeds_for_royalties,
                         buyer_leftover_after_royalties_and_house_fee,
                     )?;
@@ -548,6 +873,16 @@ pub fn handle_execute_sale_v2<'info>(
         )?;
     }
 
+    // This must happen while the token is still in the seller's token account.
+    if !Metadata::from_account_info(metadata_info)?.primary_sale_happened {
+        update_primary_sale_happened_via_token(
+            metadata_info,
+            &seller.to_account_info(),
+            &token_account.to_account_info(),
+            &metaplex_token_metadata_program.to_account_info(),
+        )?;
+    }
+
     if !seller.is_signer {
         invoke_signed(
             &spl_token::instruction::transfer_checked(
@@ -563,9 +898,9 @@ pub fn handle_execute_sale_v2<'info>(
             &[
                 token_account.to_account_info(),
                 token_mint.to_account_info(),
-                buyer_receipt_clone,
+                buyer_receipt_token_account.clone(),
                 program_as_signer.to_account_info(),
-                token_clone,
+                token_program.clone(),
             ],
             &[&program_as_signer_seeds],
         )?;
@@ -593,9 +928,9 @@ pub fn handle_execute_sale_v2<'info>(
             &[
                 token_account.to_account_info(),
                 token_mint.to_account_info(),
-                buyer_receipt_clone,
+                buyer_receipt_token_account.clone(),
                 seller.to_account_info(),
-                token_clone,
+                token_program.clone(),
             ],
         )?;
 
@@ -612,21 +947,27 @@ pub fn handle_execute_sale_v2<'info>(
 
     let curr_seller_lamp = seller_trade_state.lamports();
     **seller_trade_state.lamports.borrow_mut() = 0;
-    seller_trade_state_clone.data.borrow_mut()[0] = 0;
+    let mut seller_trade_state_data = get_trade_state(seller_trade_state)?;
+    seller_trade_state_data.bump = 0;
+    write_trade_state(seller_trade_state, &seller_trade_state_data)?;
 
     **fee_payer.lamports.borrow_mut() = fee_payer
         .lamports()
         .checked_add(curr_seller_lamp)
         .ok_or(AuctionHouseError::NumericalOverflow)?;
 
-    let curr_buyer_lamp = buyer_trade_state.lamports();
-    **buyer_trade_state.lamports.borrow_mut() = 0;
-    buyer_trade_state_clone.data.borrow_mut()[0] = 0;
+    if let Some(buyer_trade_state) = buyer_trade_state {
+        let curr_buyer_lamp = buyer_trade_state.lamports();
+        **buyer_trade_state.lamports.borrow_mut() = 0;
+        let mut buyer_trade_state_data = get_trade_state(buyer_trade_state)?;
+        buyer_trade_state_data.bump = 0;
+        write_trade_state(buyer_trade_state, &buyer_trade_state_data)?;
 
-    **fee_payer.lamports.borrow_mut() = fee_payer
-        .lamports()
-        .checked_add(curr_buyer_lamp)
-        .ok_or(AuctionHouseError::NumericalOverflow)?;
+        **fee_payer.lamports.borrow_mut() = fee_payer
+            .lamports()
+            .checked_add(curr_buyer_lamp)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+    }
 
     if free_trade_state.lamports() > 0 {
         let curr_buyer_lamp = free_trade_state.lamports();
@@ -642,6 +983,12 @@ pub fn handle_execute_sale_v2<'info>(
     last_bid_price.bidder = Some(ZERO_PUBKEY);
     last_bid_price.has_been_sold = 1;
     last_bid_price.tick_size_constant_in_lamports = 0;
+    last_bid_price.tick_size_percent = 0;
+    last_bid_price.tick_size_min_in_lamports = 0;
+    last_bid_price.tick_size_max_in_lamports = 0;
+    last_bid_price.buy_now_price = None;
+    last_bid_price.proxy_max_bid = 0;
+    last_bid_price.buyer_premium_basis_points = 0;
 
     Ok(())
 }
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, EditionDistributor,
+};
+
+#[derive(Accounts)]
+pub struct LockEditionDistributorSupply<'info> {
+    /// CHECK: Validated in instruction handler.
+    owner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [
+            EDITION_DISTRIBUTOR.as_bytes(),
+            edition_distributor.master_edition_mint.key().as_ref()
+        ],
+        bump = edition_distributor.bump,
+    )]
+    edition_distributor: Account<'info, EditionDistributor>,
+    /// CHECK: Validated in instruction handler.
+    master_edition: UncheckedAccount<'info>,
+    /// CHECK: Validated in instruction handler.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Permanently caps the distributor at the current master edition supply. The master
+ * edition token stays in the distributor token account and every instruction which
+ * mints through the distributor checks is_supply_locked, so no one can print more
+ * editions after this.
+ */
+pub fn handle_lock_edition_distributor_supply<'info>(
+    ctx: Context<'_, '_, '_, 'info, LockEditionDistributorSupply<'info>>,
+    edition_bump: u8,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let authority = &ctx.accounts.authority;
+    let edition_distributor = &mut ctx.accounts.edition_distributor;
+    let owner = &ctx.accounts.owner;
+    let master_edition = &ctx.accounts.master_edition;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::DropManager,
+        )?;
+    }
+
+    if edition_distributor.is_supply_locked {
+        return Err(AuctionHouseError::EditionSupplyLocked.into());
+    }
+
+    let supply = get_master_edition_supply(
+        master_edition,
+        &edition_distributor.master_edition_mint,
+        edition_bump,
+    )?;
+
+    let clock = Clock::get()?;
+    if !can_lock_edition_supply(
+        edition_distributor.sale_end_time,
+        edition_distributor.max_editions_to_sell,
+        supply,
+        clock.unix_timestamp,
+    ) {
+        return Err(AuctionHouseError::CannotLockEditionSupplyDuringSale.into());
+    }
+
+    edition_distributor.max_editions_to_sell = Some(supply);
+    edition_distributor.is_supply_locked = true;
+
+    msg!(
+        "Locked supply at {} editions for mint {}",
+        supply,
+        edition_distributor.master_edition_mint
+    );
+
+    Ok(())
+}
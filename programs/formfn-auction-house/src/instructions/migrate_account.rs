@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    utils::*, AuctionHouse, EditionBuyerInfoAccount, EditionDistributor, LastBidPrice,
+    AUCTION_HOUSE_SIZE, AUCTION_HOUSE_VERSION, EDITION_BUYER_INFO_ACCOUNT_SPACE,
+    EDITION_BUYER_INFO_ACCOUNT_VERSION, EDITION_DISTRIBUTOR_SIZE, EDITION_DISTRIBUTOR_VERSION,
+    LAST_BID_PRICE_SIZE, LAST_BID_PRICE_VERSION,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MigratableAccountType {
+    AuctionHouse,
+    LastBidPrice,
+    EditionDistributor,
+    EditionAllowlistSettings,
+    EditionBuyerInfoAccount,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: Validated in the instruction handler. We don't deserialize it with Anchor
+    // here because the account type is an instruction arg, and old accounts may be too small.
+    #[account(mut)]
+    account: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Migrates any program account to its latest layout, i.e. reallocs it to the current size
+ * (if it is smaller) and sets its version to the current version. This is safe to call
+ * on accounts which are already up to date. Legacy EditionAllowlistSettings accounts must
+ * be migrated before they can be used, see migrate_edition_allowlist_settings.
+ */
+pub fn handle_migrate_account<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>,
+    account_type: MigratableAccountType,
+) -> Result<()> {
+    let program_id = ctx.program_id;
+    let account = &ctx.accounts.account.to_account_info();
+    let payer = &ctx.accounts.payer.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+
+    match account_type {
+        MigratableAccountType::AuctionHouse => migrate_program_account::<AuctionHouse>(
+            program_id,
+            account,
+            payer,
+            system_program,
+            AUCTION_HOUSE_SIZE,
+            |auction_house| auction_house.version = AUCTION_HOUSE_VERSION,
+        ),
+        MigratableAccountType::LastBidPrice => migrate_program_account::<LastBidPrice>(
+            program_id,
+            account,
+            payer,
+            system_program,
+            LAST_BID_PRICE_SIZE,
+            |last_bid_price| last_bid_price.version = LAST_BID_PRICE_VERSION,
+        ),
+        MigratableAccountType::EditionDistributor => {
+            migrate_program_account::<EditionDistributor>(
+                program_id,
+                account,
+                payer,
+                system_program,
+                EDITION_DISTRIBUTOR_SIZE,
+                |edition_distributor| edition_distributor.version = EDITION_DISTRIBUTOR_VERSION,
+            )
+        }
+        MigratableAccountType::EditionAllowlistSettings => {
+            migrate_edition_allowlist_settings(program_id, account, payer, system_program)
+        }
+        MigratableAccountType::EditionBuyerInfoAccount => {
+            migrate_program_account::<EditionBuyerInfoAccount>(
+                program_id,
+                account,
+                payer,
+                system_program,
+                EDITION_BUYER_INFO_ACCOUNT_SPACE,
+                |edition_buyer_info_account| {
+                    edition_buyer_info_account.version = EDITION_BUYER_INFO_ACCOUNT_VERSION
+                },
+            )
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, TokenAccount};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, TradeState, TRADE_STATE_SIZE,
+    TRADE_STATE_VERSION,
+};
+
+#[derive(Accounts)]
+#[instruction(trade_state_bump: u8, price: u64, token_size: u64)]
+pub struct MigrateLegacyTradeState<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// CHECK: No need to deserialize.
+    authority: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    wallet: UncheckedAccount<'info>,
+    token_mint: Account<'info, Mint>,
+    #[account(owner = token::ID)]
+    token_account: Account<'info, TokenAccount>,
+    #[account(
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: Validated in the instruction handler.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &price.to_le_bytes(),
+            &token_size.to_le_bytes()
+        ],
+        bump = trade_state_bump
+    )]
+    trade_state: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Rewrites a legacy trade state as a TradeState account, which every other instruction requires.
+ * The sale type is kept, and 1 byte trade states and ones with an unknown sale type become
+ * auctions, which is how they were handled. Since the original creation time is unknown,
+ * created_at is set to the time of the migration.
+ *
+ * Note that the payer is not refunded the extra rent, since trade states are always
+ * closed to the fee payer.
+ */
+pub fn handle_migrate_legacy_trade_state<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateLegacyTradeState<'info>>,
+    _trade_state_bump: u8,
+    price: u64,
+    token_size: u64,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let authority = &ctx.accounts.authority;
+    let wallet = &ctx.accounts.wallet;
+    let token_mint = &ctx.accounts.token_mint;
+    let token_account = &ctx.accounts.token_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let trade_state = &ctx.accounts.trade_state.to_account_info();
+    let system_program = &ctx.accounts.system_program;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_keys_equal(token_mint.key(), token_account.mint)?;
+
+    if !wallet.to_account_info().is_signer && !authority.to_account_info().is_signer {
+        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    }
+
+    assert_owned_by(trade_state, ctx.program_id)?;
+    let (bump, sale_type) = parse_legacy_trade_state(&trade_state.try_borrow_data()?)?;
+
+    realloc_account_if_needed(
+        trade_state,
+        &payer.to_account_info(),
+        &system_program.to_account_info(),
+        TRADE_STATE_SIZE,
+    )?;
+
+    let (seller, buyer) = get_trade_state_parties(wallet.key(), token_account.owner);
+    write_trade_state(
+        trade_state,
+        &TradeState {
+            bump,
+            version: TRADE_STATE_VERSION,
+            sale_type: sale_type as u8,
+            seller,
+            buyer,
+            token_mint: token_mint.key(),
+            price,
+            token_size,
+            created_at: Clock::get()?.unix_timestamp,
+            expiry: None,
+        },
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+
+use crate::{
+    constants::*, create_ata_for_buy_edition, mint_next_edition, utils::*, AuctionHouseError,
+    EditionDistributor,
+};
+
+#[derive(Accounts)]
+pub struct MintReservedEdition<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [
+            EDITION_DISTRIBUTOR.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump = edition_distributor.bump,
+    )]
+    edition_distributor: Box<Account<'info, EditionDistributor>>,
+    #[account()]
+    mint: Box<Account<'info, Mint>>,
+    /// Master edition mint metadata account.
+    /// CHECK: Account is passed through to mpl program.
+    master_edition_metadata: UncheckedAccount<'info>,
+    /// Edition account of the master edition mint.
+    /// CHECK: Account is passed through to mpl program.
+    #[account(mut)]
+    master_edition_pda: UncheckedAccount<'info>,
+    /// Distributor token account for the master edition mint.
+    /// CHECK: Account is passed through to mpl program.
+    master_edition_token_account: UncheckedAccount<'info>,
+    /// SPL [TokenMetadata] program.
+    /// CHECK: Account is passed through to mpl program.
+    token_metadata_program: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    ata_program: Program<'info, AssociatedToken>,
+    rent: Sysvar<'info, Rent>,
+}
+
+// Accounts of each reserved edition in remaining_accounts, see handle_mint_reserved_edition.
+const ACCOUNTS_PER_RESERVED_EDITION: usize = 6;
+
+/**
+ * Lets the distributor owner mint a block of editions to any wallets (e.g. for the team or
+ * giveaways) without going through the sale, one edition per entry of recipients. The owner
+ * pays for the new accounts and no price or platform fee is charged. Reserved editions count
+ * against max_editions_to_sell, which is checked for the whole block up front, but are
+ * tracked separately from sales so they do not affect allowlist or Linear pricing.
+ *
+ * For each recipient, remaining_accounts must hold (in order) the recipient, its token
+ * account for the new edition, the new limited edition mint (which must sign), its metadata,
+ * its edition account and the edition marker PDA.
+ */
+pub fn handle_mint_reserved_edition<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintReservedEdition<'info>>,
+    edition_bump: u8,
+    recipients: Vec<Pubkey>,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let master_edition_pda = &ctx.accounts.master_edition_pda;
+    let mint = &ctx.accounts.mint;
+    let ata_program = &ctx.accounts.ata_program;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let rent = &ctx.accounts.rent;
+
+    if recipients.is_empty()
+        || ctx.remaining_accounts.len() != recipients.len() * ACCOUNTS_PER_RESERVED_EDITION
+    {
+        return Err(AuctionHouseError::InvalidReservedEditionAccounts.into());
+    }
+
+    let supply = get_master_edition_supply(master_edition_pda, &mint.key(), edition_bump)?;
+    let number_of_editions = recipients.len() as u64;
+    assert_edition_supply_available(
+        ctx.accounts.edition_distributor.max_editions_to_sell,
+        ctx.accounts.edition_distributor.is_supply_locked,
+        supply.checked_add(number_of_editions).unwrap(),
+    )?;
+
+    for (index, (recipient, edition_accounts)) in recipients
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_RESERVED_EDITION))
+        .enumerate()
+    {
+        let recipient_account = &edition_accounts[0];
+        let recipient_token_account = &edition_accounts[1];
+        let limited_edition_mint = &edition_accounts[2];
+        let limited_edition_metadata = &edition_accounts[3];
+        let limited_edition_pda = &edition_accounts[4];
+        let edition_marker_pda = &edition_accounts[5];
+        if recipient_account.key() != *recipient {
+            return Err(AuctionHouseError::InvalidReservedEditionAccounts.into());
+        }
+
+        let edition = supply.checked_add(index as u64 + 1).unwrap();
+
+        create_ata_for_buy_edition(
+            recipient_token_account.clone(),
+            owner.to_account_info(),
+            recipient_account.clone(),
+            limited_edition_mint.clone(),
+            ata_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+            &rent,
+        )?;
+
+        mint_next_edition(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            limited_edition_metadata.clone(),
+            limited_edition_pda.clone(),
+            ctx.accounts.master_edition_pda.to_account_info(),
+            limited_edition_mint.clone(),
+            edition_marker_pda.clone(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.edition_distributor.to_account_info(),
+            ctx.accounts.master_edition_token_account.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts.master_edition_metadata.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.edition_distributor.master_edition_mint,
+            ctx.accounts.edition_distributor.bump,
+            edition,
+        )?;
+
+        msg!(
+            "Minted reserved edition #{} for mint {} to {}",
+            edition,
+            ctx.accounts.edition_distributor.master_edition_mint,
+            recipient
+        );
+    }
+
+    let edition_distributor = &mut ctx.accounts.edition_distributor;
+    edition_distributor.reserved_number_minted = edition_distributor
+        .reserved_number_minted
+        .checked_add(number_of_editions)
+        .unwrap();
+
+    Ok(())
+}
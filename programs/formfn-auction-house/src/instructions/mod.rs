@@ -1,57 +1,145 @@
+pub mod accept_authority;
+pub mod accept_fee_withdrawal_destination;
+pub mod accept_treasury_withdrawal_destination;
 pub mod append_edition_allowlist_merkle_roots;
+pub mod apply_pending_fees;
+pub mod apply_pending_treasury_split;
+pub mod buy_edition_for_recipient;
 pub mod buy_edition_v2;
+pub mod buy_edition_v3;
+pub mod buy_now;
 pub mod buy_v2;
+pub mod buy_v2_with_max_bid;
+pub mod buy_v2_with_shared_escrow;
 pub mod cancel_v2;
+pub mod claim_balance;
 pub mod clear_edition_allowlist_merkle_roots;
 pub mod close_edition_allowlist_settings_account;
 pub mod close_edition_distributor;
 pub mod close_edition_distributor_token_account;
+pub mod close_fee_override;
+pub mod close_last_bid_price;
+pub mod close_sealed_bid_auction;
+pub mod commit_sealed_bid;
 pub mod create_auction_house;
 pub mod create_edition_distributor;
 pub mod create_last_bid_price;
+pub mod create_last_bid_price_audit_log;
+pub mod create_sealed_bid_auction;
 pub mod create_trade_state;
+pub mod create_trade_state_v2;
 pub mod deposit;
+pub mod deposit_to_shared_escrow;
+pub mod distribute_treasury;
 pub mod execute_sale_v2;
+pub mod lock_edition_distributor_supply;
+pub mod migrate_account;
+pub mod migrate_legacy_trade_state;
+pub mod mint_reserved_edition;
+pub mod refund_sealed_bid;
+pub mod release_shared_escrow_reservation;
+pub mod reveal_sealed_bid;
 pub mod sell;
+pub mod set_auction_house_roles;
+pub mod set_buy_now_price;
+pub mod set_buyer_premium_basis_points;
+pub mod set_claimable_balances_enabled;
+pub mod set_default_tick_size;
 pub mod set_edition_distributor_bot_protection_enabled;
+pub mod set_edition_distributor_bot_tax;
 pub mod set_edition_distributor_limit_per_address;
+pub mod set_edition_distributor_max_editions_to_sell;
+pub mod set_edition_distributor_revenue_split;
+pub mod set_fee_override;
+pub mod set_guardian;
 pub mod set_has_been_sold;
 pub mod set_last_bid_price;
+pub mod set_paused;
 pub mod set_previous_bidder;
+pub mod set_revenue_split;
 pub mod set_tick_size;
+pub mod set_treasury_split;
+pub mod settle_shared_escrow_reservation;
+pub mod sweep_auction_house_token_account;
+pub mod sweep_edition_distributor_token_account;
 pub mod thaw_delegated_account;
 pub mod update_auction_house;
 pub mod update_edition_distributor;
 pub mod withdraw;
 pub mod withdraw_bonk;
 pub mod withdraw_from_fee;
+pub mod withdraw_from_shared_escrow;
 pub mod withdraw_from_treasury;
 
+pub use accept_authority::*;
+pub use accept_fee_withdrawal_destination::*;
+pub use accept_treasury_withdrawal_destination::*;
 pub use append_edition_allowlist_merkle_roots::*;
+pub use apply_pending_fees::*;
+pub use apply_pending_treasury_split::*;
+pub use buy_edition_for_recipient::*;
 pub use buy_edition_v2::*;
+pub use buy_edition_v3::*;
+pub use buy_now::*;
 pub use buy_v2::*;
+pub use buy_v2_with_max_bid::*;
+pub use buy_v2_with_shared_escrow::*;
 pub use cancel_v2::*;
+pub use claim_balance::*;
 pub use clear_edition_allowlist_merkle_roots::*;
 pub use close_edition_allowlist_settings_account::*;
 pub use close_edition_distributor::*;
 pub use close_edition_distributor_token_account::*;
+pub use close_fee_override::*;
+pub use close_last_bid_price::*;
+pub use close_sealed_bid_auction::*;
+pub use commit_sealed_bid::*;
 pub use create_auction_house::*;
 pub use create_edition_distributor::*;
 pub use create_last_bid_price::*;
+pub use create_last_bid_price_audit_log::*;
+pub use create_sealed_bid_auction::*;
 pub use create_trade_state::*;
+pub use create_trade_state_v2::*;
 pub use deposit::*;
+pub use deposit_to_shared_escrow::*;
+pub use distribute_treasury::*;
 pub use execute_sale_v2::*;
+pub use lock_edition_distributor_supply::*;
+pub use migrate_account::*;
+pub use migrate_legacy_trade_state::*;
+pub use mint_reserved_edition::*;
+pub use refund_sealed_bid::*;
+pub use release_shared_escrow_reservation::*;
+pub use reveal_sealed_bid::*;
 pub use sell::*;
+pub use set_auction_house_roles::*;
+pub use set_buy_now_price::*;
+pub use set_buyer_premium_basis_points::*;
+pub use set_claimable_balances_enabled::*;
+pub use set_default_tick_size::*;
 pub use set_edition_distributor_bot_protection_enabled::*;
+pub use set_edition_distributor_bot_tax::*;
 pub use set_edition_distributor_limit_per_address::*;
+pub use set_edition_distributor_max_editions_to_sell::*;
+pub use set_edition_distributor_revenue_split::*;
+pub use set_fee_override::*;
+pub use set_guardian::*;
 pub use set_has_been_sold::*;
 pub use set_last_bid_price::*;
+pub use set_paused::*;
 pub use set_previous_bidder::*;
+pub use set_revenue_split::*;
 pub use set_tick_size::*;
+pub use set_treasury_split::*;
+pub use settle_shared_escrow_reservation::*;
+pub use sweep_auction_house_token_account::*;
+pub use sweep_edition_distributor_token_account::*;
 pub use thaw_delegated_account::*;
 pub use update_auction_house::*;
 pub use update_edition_distributor::*;
 pub use withdraw::*;
 pub use withdraw_bonk::*;
 pub use withdraw_from_fee::*;
+pub use withdraw_from_shared_escrow::*;
 pub use withdraw_from_treasury::*;
@@ -0,0 +1,167 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, SealedBid, SealedBidAuction,
+};
+
+#[derive(Accounts)]
+pub struct RefundSealedBid<'info> {
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    bidder: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    receipt_account: UncheckedAccount<'info>,
+    treasury_mint: Account<'info, Mint>,
+    /// CHECK: No need to deserialize.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        has_one = treasury_mint,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    /// CHECK: No need to deserialize.
+    token_mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = auction_house,
+        has_one = token_mint,
+        seeds = [
+            SEALED_BID_AUCTION.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = sealed_bid_auction.bump
+    )]
+    sealed_bid_auction: Account<'info, SealedBidAuction>,
+    #[account(
+        mut,
+        close = bidder,
+        has_one = bidder,
+        has_one = sealed_bid_auction,
+        seeds = [
+            SEALED_BID.as_bytes(),
+            sealed_bid_auction.key().as_ref(),
+            bidder.key().as_ref()
+        ],
+        bump = sealed_bid.bump
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            sealed_bid.key().as_ref(),
+            SEALED_BID_ESCROW.as_bytes()
+        ],
+        bump = sealed_bid.escrow_bump
+    )]
+    sealed_bid_escrow: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Refunds a sealed bid's deposit from its escrow once the reveal phase has ended, and closes
+ * the SealedBid (and the escrow, for SPL auction houses). Losing (and unrevealed) bids are
+ * refunded in full. The winner is refunded whatever is left over after paying the clearing
+ * price, so their bid can only be refunded after the sale is executed, unless the auction
+ * house authority signs (e.g. if the sale can't be executed).
+ *
+ * Either the bidder or the auction house authority must sign. The refund goes to the
+ * bidder, or their associated token account for SPL auction houses.
+ */
+pub fn handle_refund_sealed_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundSealedBid<'info>>,
+) -> Result<()> {
+    let bidder = &ctx.accounts.bidder;
+    let receipt_account = &ctx.accounts.receipt_account;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let authority = &ctx.accounts.authority;
+    let auction_house = &ctx.accounts.auction_house;
+    let sealed_bid_auction = &mut ctx.accounts.sealed_bid_auction;
+    let sealed_bid = &ctx.accounts.sealed_bid;
+    let sealed_bid_escrow = &ctx.accounts.sealed_bid_escrow;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if !bidder.is_signer && !authority.is_signer {
+        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    }
+
+    if Clock::get()?.unix_timestamp < sealed_bid_auction.reveal_end_time {
+        return Err(AuctionHouseError::SealedBidAuctionRevealNotEnded.into());
+    }
+
+    let is_winner = sealed_bid_auction.highest_bidder == Some(bidder.key());
+    if is_winner && !sealed_bid_auction.is_settled && !authority.is_signer {
+        return Err(AuctionHouseError::SealedBidWinnerNotSettled.into());
+    }
+
+    // After settlement the winner's escrow only holds what is left over from their deposit.
+    let refund_amount = get_sealed_bid_escrow_balance(&sealed_bid_escrow.to_account_info())?;
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+    if is_native {
+        assert_keys_equal(receipt_account.key(), bidder.key())?;
+    } else {
+        assert_is_ata(receipt_account, &bidder.key(), &treasury_mint.key())?;
+    }
+
+    if refund_amount > 0 {
+        transfer_from_sealed_bid_escrow(
+            &sealed_bid_escrow.to_account_info(),
+            &receipt_account.to_account_info(),
+            auction_house,
+            &token_program.to_account_info(),
+            &system_program.to_account_info(),
+            &sealed_bid.key(),
+            sealed_bid.escrow_bump,
+            refund_amount,
+            is_native,
+        )?;
+    }
+
+    if !is_native {
+        let ah_seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            &[auction_house.bump],
+        ];
+        invoke_signed(
+            &spl_token::instruction::close_account(
+                &token_program.key(),
+                &sealed_bid_escrow.key(),
+                &bidder.key(),
+                &auction_house.key(),
+                &[],
+            )?,
+            &[
+                sealed_bid_escrow.to_account_info(),
+                bidder.to_account_info(),
+                auction_house.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            &[&ah_seeds],
+        )?;
+    }
+
+    sealed_bid_auction.number_of_refunds = sealed_bid_auction
+        .number_of_refunds
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice, SharedEscrow};
+
+#[derive(Accounts)]
+pub struct ReleaseSharedEscrowReservation<'info> {
+    authority: Signer<'info>,
+    /// CHECK: No need to deserialize.
+    wallet: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            SHARED_ESCROW.as_bytes()
+        ],
+        bump = shared_escrow.bump
+    )]
+    shared_escrow: Account<'info, SharedEscrow>,
+    /// CHECK: No need to deserialize.
+    token_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    last_bid_price: Account<'info, LastBidPrice>,
+}
+
+/**
+ * Lets the auction house authority cancel the current bid on an auction when it is backed
+ * by a shared escrow reservation, releasing the reservation and resetting the bid. This is
+ * the shared escrow equivalent of resetting a bid with set_last_bid_price, which is not
+ * allowed while a reservation exists since it would leave the funds reserved.
+ */
+pub fn handle_release_shared_escrow_reservation(
+    ctx: Context<ReleaseSharedEscrowReservation>,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let auction_house = &ctx.accounts.auction_house;
+    let shared_escrow = &mut ctx.accounts.shared_escrow;
+    let token_mint = &ctx.accounts.token_mint;
+    let last_bid_price = &mut ctx.accounts.last_bid_price;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_valid_last_bid_price(
+        &last_bid_price.to_account_info(),
+        ctx.program_id,
+        &token_mint.key(),
+    )?;
+
+    if !last_bid_price.bidder_uses_shared_escrow || last_bid_price.bidder != Some(wallet.key())
+    {
+        return Err(AuctionHouseError::NoSharedEscrowReservation.into());
+    }
+
+    release_shared_escrow_amount(shared_escrow, get_escrowed_bid_amount(last_bid_price))?;
+
+    last_bid_price.price = 0;
+    last_bid_price.proxy_max_bid = 0;
+    last_bid_price.buyer_premium_basis_points = 0;
+    last_bid_price.bidder = Some(ZERO_PUBKEY);
+    last_bid_price.bidder_uses_shared_escrow = false;
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouseError, SealedBid, SealedBidAuction};
+
+#[derive(Accounts)]
+pub struct RevealSealedBid<'info> {
+    /// CHECK: No need to deserialize. Knowing the salt is enough to reveal a bid, so the
+    /// bidder doesn't need to sign.
+    bidder: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SEALED_BID_AUCTION.as_bytes(),
+            sealed_bid_auction.auction_house.as_ref(),
+            sealed_bid_auction.token_mint.as_ref()
+        ],
+        bump = sealed_bid_auction.bump
+    )]
+    sealed_bid_auction: Account<'info, SealedBidAuction>,
+    #[account(
+        mut,
+        has_one = bidder,
+        has_one = sealed_bid_auction,
+        seeds = [
+            SEALED_BID.as_bytes(),
+            sealed_bid_auction.key().as_ref(),
+            bidder.key().as_ref()
+        ],
+        bump = sealed_bid.bump
+    )]
+    sealed_bid: Account<'info, SealedBid>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            sealed_bid_auction.auction_house.as_ref(),
+            sealed_bid.key().as_ref(),
+            SEALED_BID_ESCROW.as_bytes()
+        ],
+        bump = sealed_bid.escrow_bump
+    )]
+    sealed_bid_escrow: UncheckedAccount<'info>,
+}
+
+/**
+ * Reveals a sealed bid during the reveal phase, i.e. after commit_end_time and before
+ * reveal_end_time. Bids below the reserve price are revealed but can't win.
+ */
+pub fn handle_reveal_sealed_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealSealedBid<'info>>,
+    price: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let bidder = &ctx.accounts.bidder;
+    let sealed_bid_auction = &mut ctx.accounts.sealed_bid_auction;
+    let sealed_bid = &mut ctx.accounts.sealed_bid;
+    let sealed_bid_escrow = &ctx.accounts.sealed_bid_escrow;
+
+    let unix_timestamp = Clock::get()?.unix_timestamp;
+    if unix_timestamp < sealed_bid_auction.commit_end_time
+        || unix_timestamp >= sealed_bid_auction.reveal_end_time
+    {
+        return Err(AuctionHouseError::SealedBidNotInRevealPhase.into());
+    }
+
+    if sealed_bid.revealed_price.is_some() {
+        return Err(AuctionHouseError::SealedBidAlreadyRevealed.into());
+    }
+
+    if get_sealed_bid_commitment(price, &salt, &bidder.key()) != sealed_bid.commitment {
+        return Err(AuctionHouseError::InvalidSealedBidReveal.into());
+    }
+
+    // The escrow can only be paid out of after the reveal phase, but check what it actually
+    // holds rather than trusting the recorded deposit.
+    let escrow_balance = get_sealed_bid_escrow_balance(&sealed_bid_escrow.to_account_info())?;
+    if price > sealed_bid.deposit || price > escrow_balance {
+        return Err(AuctionHouseError::SealedBidExceedsDeposit.into());
+    }
+
+    sealed_bid.revealed_price = Some(price);
+    sealed_bid_auction.number_of_reveals = sealed_bid_auction
+        .number_of_reveals
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    if price >= sealed_bid_auction.reserve_price {
+        record_sealed_bid_reveal(sealed_bid_auction, &bidder.key(), price);
+    }
+
+    Ok(())
+}
@@ -5,7 +5,10 @@ use anchor_lang::{
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use spl_token::instruction::approve;
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, TradeState, TradeStateSaleType,
+    TRADE_STATE_SIZE, TRADE_STATE_VERSION,
+};
 
 #[derive(Accounts)]
 #[instruction(trade_state_bump: u8, free_trade_state_bump: u8, program_as_signer_bump: u8, buyer_price: u64, token_size: u64)]
@@ -122,8 +125,11 @@ pub fn handle_sell<'info>(
     let metaplex_token_metadata_program = &ctx.accounts.metaplex_token_metadata_program;
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
 
-    let sale_type = get_trade_state_sale_type(&seller_trade_state.to_account_info());
+    let clock = Clock::get()?;
+    let sale_type =
+        get_sell_or_buy_sale_type(&seller_trade_state.to_account_info(), clock.unix_timestamp)?;
     msg!("seller_sale_type = {}", sale_type);
     assert_keys_equal(token_mint.key(), token_account.mint)?;
     assert_token_account_owner(token_account.owner, wallet.key())?;
@@ -206,15 +212,27 @@ pub fn handle_sell<'info>(
             &rent.to_account_info(),
             &system_program,
             &fee_payer,
-            1 as usize,
+            TRADE_STATE_SIZE,
             fee_seeds,
             &ts_seeds,
         )?;
+        write_trade_state(
+            &ts_info,
+            &TradeState {
+                bump: trade_state_bump,
+                version: TRADE_STATE_VERSION,
+                sale_type: TradeStateSaleType::Auction as u8,
+                seller: wallet_key,
+                buyer: Pubkey::default(),
+                token_mint: token_mint.key(),
+                price: buyer_price,
+                token_size,
+                created_at: clock.unix_timestamp,
+                expiry: None,
+            },
+        )?;
     }
 
-    let data = &mut ts_info.data.borrow_mut();
-    data[0] = trade_state_bump;
-
     let program_as_signer_seeds = [
         PREFIX.as_bytes(),
         SIGNER.as_bytes(),
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseRoles, AUCTION_HOUSE_ROLES_SIZE,
+    AUCTION_HOUSE_ROLES_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct SetAuctionHouseRoles<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AUCTION_HOUSE_ROLES_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            AUCTION_HOUSE_ROLES.as_bytes()
+        ],
+        bump
+    )]
+    auction_house_roles: Account<'info, AuctionHouseRoles>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Assigns the admin roles of the auction house, see AuctionHouseRoles. Pass the default
+ * pubkey to leave a role unassigned. Only the authority can assign roles.
+ */
+pub fn handle_set_auction_house_roles(
+    ctx: Context<SetAuctionHouseRoles>,
+    fee_manager: Pubkey,
+    treasury_manager: Pubkey,
+    listing_moderator: Pubkey,
+    drop_manager: Pubkey,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_roles = &mut ctx.accounts.auction_house_roles;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    auction_house_roles.auction_house = auction_house.key();
+    auction_house_roles.fee_manager = fee_manager;
+    auction_house_roles.treasury_manager = treasury_manager;
+    auction_house_roles.listing_moderator = listing_moderator;
+    auction_house_roles.drop_manager = drop_manager;
+    auction_house_roles.bump = *ctx.bumps.get("auction_house_roles").unwrap();
+    auction_house_roles.version = AUCTION_HOUSE_ROLES_VERSION;
+
+    Ok(())
+}
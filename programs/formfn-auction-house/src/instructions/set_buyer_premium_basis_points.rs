@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct SetBuyerPremiumBasisPoints<'info> {
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Sets the buyer premium, which is charged to the winner of an auction on top of the winning
+ * bid. buy_v2 escrows the premium with each auction bid and execute_sale_v2 pays it to the
+ * treasury. Bids which were placed before a change keep the premium they were placed with.
+ */
+pub fn handle_set_buyer_premium_basis_points(
+    ctx: Context<SetBuyerPremiumBasisPoints>,
+    buyer_premium_basis_points: u16,
+) -> Result<()> {
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if buyer_premium_basis_points > 10000 {
+        return Err(AuctionHouseError::InvalidBasisPoints.into());
+    }
+
+    auction_house.buyer_premium_basis_points = buyer_premium_basis_points;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse};
+
+#[derive(Accounts)]
+pub struct SetClaimableBalancesEnabled<'info> {
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * When enabled, execute_sale_v2 credits sale proceeds to ClaimableBalance accounts instead of
+ * paying creators and sellers directly, so a sale doesn't need creator token accounts and
+ * can't be blocked by a malformed creator account. For SPL auction houses the proceeds are
+ * held in each ClaimableBalance's associated token account.
+ */
+pub fn handle_set_claimable_balances_enabled(
+    ctx: Context<SetClaimableBalancesEnabled>,
+    claimable_balances_enabled: bool,
+) -> Result<()> {
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    auction_house.claimable_balances_enabled = claimable_balances_enabled;
+
+    Ok(())
+}
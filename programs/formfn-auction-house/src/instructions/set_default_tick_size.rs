@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{constants::*, utils::*, AuctionHouse, TickSize};
+
+#[derive(Accounts)]
+pub struct SetDefaultTickSize<'info> {
+    authority: Signer<'info>,
+    treasury_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        has_one = authority,
+        has_one = treasury_mint,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            treasury_mint.key().as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Sets the tick size used by auctions of this auction house which don't set their own tick
+ * size with set_tick_size. Setting all values to 0 falls back to the default of 10%.
+ */
+pub fn handle_set_default_tick_size<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetDefaultTickSize<'info>>,
+    default_tick_size: TickSize,
+) -> Result<()> {
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_valid_tick_size(&default_tick_size, treasury_mint.decimals)?;
+
+    auction_house.default_tick_size = default_tick_size;
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, EditionDistributor};
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseRole, EditionDistributor};
 
 #[derive(Accounts)]
 pub struct SetEditionDistributorBotProtectionEnabled<'info> {
@@ -22,7 +22,6 @@ pub struct SetEditionDistributorBotProtectionEnabled<'info> {
     /// CHECK: Validated in instruction handler.
     authority: UncheckedAccount<'info>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -44,8 +43,14 @@ pub fn handle_set_edition_distributor_bot_protection_enabled<'info>(
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
 
-    if !owner.to_account_info().is_signer && !authority.to_account_info().is_signer {
-        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::DropManager,
+        )?;
     }
 
     edition_distributor.anti_bot_protection_enabled = anti_bot_protection_enabled;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, EditionDistributor,
+};
+
+#[derive(Accounts)]
+pub struct SetEditionDistributorBotTax<'info> {
+    /// CHECK: No need to deserialize.
+    owner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [
+            EDITION_DISTRIBUTOR.as_bytes(),
+            edition_distributor.master_edition_mint.key().as_ref()
+        ],
+        bump = edition_distributor.bump,
+    )]
+    edition_distributor: Account<'info, EditionDistributor>,
+    // Only the auction house authority (or its drop manager) can configure the bot tax,
+    // since it is part of the platform's anti-bot protection.
+    authority: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+pub fn handle_set_edition_distributor_bot_tax<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetEditionDistributorBotTax<'info>>,
+    bot_tax_amount: Option<u64>,
+    bot_tax_recipient: Option<Pubkey>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let edition_distributor = &mut ctx.accounts.edition_distributor;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::DropManager,
+    )?;
+
+    let is_native = edition_distributor.treasury_mint == spl_token::native_mint::id();
+    // Without an amount the tax for non-native distributors is charged in lamports and goes
+    // to the auction house fee account, so the recipient would never be used.
+    if !is_native && bot_tax_recipient.is_some() && bot_tax_amount.is_none() {
+        return Err(AuctionHouseError::InvalidBotTaxConfig.into());
+    }
+
+    edition_distributor.bot_tax_amount = bot_tax_amount;
+    edition_distributor.bot_tax_recipient = bot_tax_recipient;
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, EditionDistributor};
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseRole, EditionDistributor};
 
 #[derive(Accounts)]
 pub struct SetEditionDistributorLimitPerAddress<'info> {
@@ -19,7 +19,6 @@ pub struct SetEditionDistributorLimitPerAddress<'info> {
     /// CHECK: Validated in instruction handler.
     authority: UncheckedAccount<'info>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -41,8 +40,14 @@ pub fn handle_set_edition_distributor_limit_per_address<'info>(
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
 
-    if !owner.to_account_info().is_signer && !authority.to_account_info().is_signer {
-        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::DropManager,
+        )?;
     }
 
     edition_distributor.limit_per_address = limit_per_address;
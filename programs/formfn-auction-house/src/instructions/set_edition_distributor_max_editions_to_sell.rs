@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, EditionDistributor,
+};
+
+#[derive(Accounts)]
+pub struct SetEditionDistributorMaxEditionsToSell<'info> {
+    /// CHECK: Validated in instruction handler.
+    owner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [
+            EDITION_DISTRIBUTOR.as_bytes(),
+            edition_distributor.master_edition_mint.key().as_ref()
+        ],
+        bump = edition_distributor.bump,
+    )]
+    edition_distributor: Account<'info, EditionDistributor>,
+    /// CHECK: Validated in instruction handler.
+    master_edition: UncheckedAccount<'info>,
+    /// CHECK: Validated in instruction handler.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+pub fn handle_set_edition_distributor_max_editions_to_sell<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetEditionDistributorMaxEditionsToSell<'info>>,
+    edition_bump: u8,
+    max_editions_to_sell: Option<u64>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let authority = &ctx.accounts.authority;
+    let edition_distributor = &mut ctx.accounts.edition_distributor;
+    let owner = &ctx.accounts.owner;
+    let master_edition = &ctx.accounts.master_edition;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::DropManager,
+        )?;
+    }
+
+    if edition_distributor.is_supply_locked {
+        return Err(AuctionHouseError::EditionSupplyLocked.into());
+    }
+
+    let supply = get_master_edition_supply(
+        master_edition,
+        &edition_distributor.master_edition_mint,
+        edition_bump,
+    )?;
+
+    if let Some(max_editions_to_sell_val) = max_editions_to_sell {
+        if max_editions_to_sell_val < supply {
+            msg!(
+                "Invalid max_editions_to_sell: max_editions_to_sell = {}, supply = {}",
+                max_editions_to_sell_val,
+                supply
+            );
+            return Err(AuctionHouseError::InvalidMaxEditionsToSell.into());
+        }
+    }
+
+    edition_distributor.max_editions_to_sell = max_editions_to_sell;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, EditionDistributor, RevenueSplit, RevenueSplitRecipient,
+    REVENUE_SPLIT_SIZE, REVENUE_SPLIT_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct SetEditionDistributorRevenueSplit<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [
+            EDITION_DISTRIBUTOR.as_bytes(),
+            edition_distributor.master_edition_mint.key().as_ref()
+        ],
+        bump = edition_distributor.bump,
+    )]
+    edition_distributor: Account<'info, EditionDistributor>,
+    /// Master edition mint metadata account.
+    /// CHECK: Validated in the instruction handler.
+    master_edition_metadata: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = REVENUE_SPLIT_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            edition_distributor.master_edition_mint.as_ref(),
+            REVENUE_SPLIT.as_bytes()
+        ],
+        bump
+    )]
+    revenue_split: Account<'info, RevenueSplit>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Splits the proceeds of edition sales between the given recipients in buy_edition_v2,
+ * instead of paying the distributor owner or the metadata creators. Passing no recipients
+ * disables the split.
+ *
+ * Only the distributor owner can set the split, and for multi-creator master editions every
+ * verified creator must also sign, passing themselves in remaining_accounts.
+ */
+pub fn handle_set_edition_distributor_revenue_split(
+    ctx: Context<SetEditionDistributorRevenueSplit>,
+    recipients: Vec<RevenueSplitRecipient>,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let master_edition_metadata = &ctx.accounts.master_edition_metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let edition_distributor = &mut ctx.accounts.edition_distributor;
+    let revenue_split = &mut ctx.accounts.revenue_split;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        &master_edition_metadata.to_account_info(),
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            edition_distributor.master_edition_mint.as_ref(),
+        ],
+    )?;
+    let metadata = Metadata::from_account_info(master_edition_metadata)?;
+    assert_revenue_split_signed_by_creators(
+        &metadata,
+        &get_signer_keys(owner, ctx.remaining_accounts),
+    )?;
+
+    assert_valid_revenue_split_recipients(&recipients)?;
+
+    edition_distributor.has_revenue_split = !recipients.is_empty();
+    revenue_split.auction_house = auction_house.key();
+    revenue_split.mint = edition_distributor.master_edition_mint;
+    revenue_split.recipients = recipients;
+    revenue_split.bump = *ctx.bumps.get("revenue_split").unwrap();
+    revenue_split.version = REVENUE_SPLIT_VERSION;
+
+    Ok(())
+}
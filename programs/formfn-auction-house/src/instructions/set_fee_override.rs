@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, FeeOverride,
+    FEE_OVERRIDE_SIZE, FEE_OVERRIDE_VERSION,
+};
+
+#[derive(Accounts)]
+#[instruction(key: Pubkey)]
+pub struct SetFeeOverride<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    authority: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = FEE_OVERRIDE_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            key.as_ref(),
+            FEE_OVERRIDE.as_bytes()
+        ],
+        bump
+    )]
+    fee_override: Account<'info, FeeOverride>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Overrides the auction house's platform fees for a mint, an edition distributor (keyed by
+ * its master edition mint) or a creator, until the optional expiry. Sales apply it if its
+ * account is passed, see get_fee_override_from_remaining_accounts.
+ *
+ * Overrides can only lower fees, so they can't be higher than the auction house's current
+ * fees, and are also capped at them when applied (see get_seller_fee_basis_points).
+ */
+pub fn handle_set_fee_override(
+    ctx: Context<SetFeeOverride>,
+    key: Pubkey,
+    seller_fee_basis_points: u16,
+    seller_fee_basis_points_secondary: u16,
+    expiry: Option<i64>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let fee_override = &mut ctx.accounts.fee_override;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::FeeManager,
+    )?;
+
+    if seller_fee_basis_points > 10000 || seller_fee_basis_points_secondary > 10000 {
+        return Err(AuctionHouseError::InvalidBasisPoints.into());
+    }
+
+    if seller_fee_basis_points > auction_house.seller_fee_basis_points
+        || seller_fee_basis_points_secondary > auction_house.seller_fee_basis_points_secondary
+    {
+        return Err(AuctionHouseError::FeeOverrideExceedsAuctionHouseFees.into());
+    }
+
+    fee_override.auction_house = auction_house.key();
+    fee_override.key = key;
+    fee_override.seller_fee_basis_points = seller_fee_basis_points;
+    fee_override.seller_fee_basis_points_secondary = seller_fee_basis_points_secondary;
+    fee_override.expiry = expiry;
+    fee_override.bump = *ctx.bumps.get("fee_override").unwrap();
+    fee_override.version = FEE_OVERRIDE_VERSION;
+
+    Ok(())
+}
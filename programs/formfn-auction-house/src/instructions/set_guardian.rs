@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Sets the guardian, which can pause and unpause the auction house with set_paused. Pass the
+ * default pubkey to remove the guardian.
+ */
+pub fn handle_set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    auction_house.guardian = guardian;
+
+    Ok(())
+}
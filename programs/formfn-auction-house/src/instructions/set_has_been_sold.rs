@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
-use crate::{constants::*, utils::*, AuctionHouse, LastBidPrice};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseRole, LastBidPrice, LastBidPriceOverrideKind,
+};
 
 #[derive(Accounts)]
 pub struct SetHasBeenSold<'info> {
@@ -9,7 +11,6 @@ pub struct SetHasBeenSold<'info> {
     #[account()]
     token_mint: Account<'info, Mint>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -31,16 +32,36 @@ pub fn handle_set_has_been_sold<'info>(
     let token_mint = &ctx.accounts.token_mint;
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::ListingModerator,
+    )?;
     assert_valid_last_bid_price(
         &last_bid_price.to_account_info(),
         ctx.program_id,
         &token_mint.key(),
     )?;
 
+    let old_snapshot = get_last_bid_price_snapshot(last_bid_price);
+
     last_bid_price.has_been_sold = match has_been_sold {
         false => 0,
         true => 1,
     };
 
+    record_last_bid_price_override(
+        ctx.remaining_accounts,
+        ctx.program_id,
+        &auction_house.key(),
+        &token_mint.key(),
+        last_bid_price,
+        LastBidPriceOverrideKind::SetHasBeenSold,
+        &ctx.accounts.authority.key(),
+        old_snapshot,
+    )?;
+
     Ok(())
 }
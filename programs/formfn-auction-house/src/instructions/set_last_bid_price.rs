@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, TokenAccount};
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, LastBidPrice,
+    LastBidPriceOverrideKind,
+};
 
 #[derive(Accounts)]
 pub struct SetLastBidPrice<'info> {
@@ -15,7 +18,6 @@ pub struct SetLastBidPrice<'info> {
     )]
     token_account: Account<'info, TokenAccount>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -45,18 +47,48 @@ pub fn handle_set_last_bid_price<'info>(
         &token_account.mint,
     )?;
 
-    if !owner.to_account_info().is_signer && !authority.to_account_info().is_signer {
-        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::ListingModerator,
+        )?;
     }
 
     if token_account.amount == 0 {
         return Err(AuctionHouseError::InvalidTokenAmount.into());
     }
 
+    if last_bid_price.bidder_uses_shared_escrow {
+        return Err(AuctionHouseError::LastBidPriceHasSharedEscrowReservation.into());
+    }
+
+    let old_snapshot = get_last_bid_price_snapshot(last_bid_price);
+
     last_bid_price.price = price;
     if price == 0 {
         last_bid_price.bidder = Some(ZERO_PUBKEY);
+        last_bid_price.proxy_max_bid = 0;
+        last_bid_price.buyer_premium_basis_points = 0;
     }
 
+    let signer = if owner.to_account_info().is_signer {
+        owner.key()
+    } else {
+        authority.key()
+    };
+    record_last_bid_price_override(
+        ctx.remaining_accounts,
+        ctx.program_id,
+        &auction_house.key(),
+        &token_account.mint,
+        last_bid_price,
+        LastBidPriceOverrideKind::SetLastBidPrice,
+        &signer,
+        old_snapshot,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    // The auction house authority or guardian (pausing only).
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+}
+
+/**
+ * Emergency switch. While paused, nothing new can be listed, bid on, sold or paid out:
+ * sell, buy_v2, execute_sale_v2 and buy_edition_v2 (and the instructions built on them),
+ * deposit_to_shared_escrow, settle_shared_escrow_reservation, create_sealed_bid_auction,
+ * commit_sealed_bid and distribute_treasury are rejected. Users can still get their own
+ * funds and NFTs back with cancel_v2, withdraw, withdraw_from_shared_escrow,
+ * release_shared_escrow_reservation, reveal_sealed_bid, refund_sealed_bid, claim_balance and
+ * thaw_delegated_account. mint_reserved_edition is not tied to an auction house and is not
+ * affected.
+ *
+ * The authority or the guardian can pause, but only the authority can unpause.
+ */
+pub fn handle_set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let signer = &ctx.accounts.signer;
+    let auction_house = &mut ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    let is_guardian =
+        auction_house.guardian != ZERO_PUBKEY && signer.key() == auction_house.guardian;
+    if signer.key() != auction_house.authority && !is_guardian {
+        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    }
+    if !paused && signer.key() != auction_house.authority {
+        return Err(AuctionHouseError::OnlyAuthorityCanUnpause.into());
+    }
+
+    auction_house.paused = paused;
+
+    Ok(())
+}
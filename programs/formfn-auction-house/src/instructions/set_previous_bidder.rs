@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, LastBidPrice,
+    LastBidPriceOverrideKind,
+};
 
 #[derive(Accounts)]
 pub struct SetPreviousBidder<'info> {
@@ -9,7 +12,6 @@ pub struct SetPreviousBidder<'info> {
     #[account()]
     token_mint: Account<'info, Mint>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -30,6 +32,13 @@ pub fn handle_set_previous_bidder<'info>(
     let token_mint = &ctx.accounts.token_mint;
 
     assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::ListingModerator,
+    )?;
 
     let last_bid_price = &mut ctx.accounts.last_bid_price;
     assert_valid_last_bid_price(
@@ -38,6 +47,12 @@ pub fn handle_set_previous_bidder<'info>(
         &token_mint.key(),
     )?;
 
+    if last_bid_price.bidder_uses_shared_escrow {
+        return Err(AuctionHouseError::LastBidPriceHasSharedEscrowReservation.into());
+    }
+
+    let old_snapshot = get_last_bid_price_snapshot(last_bid_price);
+
     match bidder {
         None => {
             if last_bid_price.price != 0 && last_bid_price.bidder != None {
@@ -50,5 +65,16 @@ pub fn handle_set_previous_bidder<'info>(
         }
     };
 
+    record_last_bid_price_override(
+        ctx.remaining_accounts,
+        ctx.program_id,
+        &auction_house.key(),
+        &token_mint.key(),
+        last_bid_price,
+        LastBidPriceOverrideKind::SetPreviousBidder,
+        &ctx.accounts.authority.key(),
+        old_snapshot,
+    )?;
+
     Ok(())
 }
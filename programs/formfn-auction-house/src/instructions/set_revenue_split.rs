@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, TokenAccount};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice, RevenueSplit,
+    RevenueSplitRecipient, REVENUE_SPLIT_SIZE, REVENUE_SPLIT_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct SetRevenueSplit<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    owner: Signer<'info>,
+    #[account(
+        has_one = owner,
+        has_one = mint,
+        owner = token::ID
+    )]
+    token_account: Account<'info, TokenAccount>,
+    mint: Account<'info, Mint>,
+    /// CHECK: Validated in the instruction handler.
+    metadata: UncheckedAccount<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(mut)]
+    last_bid_price: Account<'info, LastBidPrice>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = REVENUE_SPLIT_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            mint.key().as_ref(),
+            REVENUE_SPLIT.as_bytes()
+        ],
+        bump
+    )]
+    revenue_split: Account<'info, RevenueSplit>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Splits the primary sale proceeds of the mint between the given recipients in
+ * execute_sale_v2, instead of paying the seller or the metadata creators. Passing no
+ * recipients disables the split.
+ *
+ * Only the token owner can set the split, and for multi-creator mints every verified
+ * creator must also sign, passing themselves in remaining_accounts.
+ */
+pub fn handle_set_revenue_split(
+    ctx: Context<SetRevenueSplit>,
+    recipients: Vec<RevenueSplitRecipient>,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let token_account = &ctx.accounts.token_account;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let last_bid_price = &mut ctx.accounts.last_bid_price;
+    let revenue_split = &mut ctx.accounts.revenue_split;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_valid_last_bid_price(
+        &last_bid_price.to_account_info(),
+        ctx.program_id,
+        &token_account.mint,
+    )?;
+
+    if token_account.amount == 0 {
+        return Err(AuctionHouseError::InvalidTokenAmount.into());
+    }
+
+    assert_metadata_valid(metadata, token_account)?;
+    let metadata = Metadata::from_account_info(metadata)?;
+    assert_revenue_split_signed_by_creators(
+        &metadata,
+        &get_signer_keys(owner, ctx.remaining_accounts),
+    )?;
+
+    assert_valid_revenue_split_recipients(&recipients)?;
+
+    last_bid_price.has_revenue_split = !recipients.is_empty();
+    revenue_split.auction_house = auction_house.key();
+    revenue_split.mint = token_account.mint;
+    revenue_split.recipients = recipients;
+    revenue_split.bump = *ctx.bumps.get("revenue_split").unwrap();
+    revenue_split.version = REVENUE_SPLIT_VERSION;
+
+    Ok(())
+}
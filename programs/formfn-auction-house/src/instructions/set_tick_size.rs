@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, TokenAccount};
 
-use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice};
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole, LastBidPrice,
+    TickSize,
+};
 
 #[derive(Accounts)]
 pub struct SetTickSize<'info> {
@@ -17,7 +20,6 @@ pub struct SetTickSize<'info> {
     token_account: Account<'info, TokenAccount>,
     mint: Account<'info, Mint>,
     #[account(
-        has_one = authority,
         seeds = [
             PREFIX.as_bytes(),
             auction_house.creator.as_ref(),
@@ -31,13 +33,16 @@ pub struct SetTickSize<'info> {
     treasury_mint: Account<'info, Mint>,
 }
 
+/**
+ * Sets the tick size of a listing, see TickSize. Setting all values to 0 makes the listing use
+ * the auction house's default tick size.
+ */
 pub fn handle_set_tick_size<'info>(
     ctx: Context<'_, '_, '_, 'info, SetTickSize<'info>>,
     tick_size_constant_in_full_decimals: u64,
-    // These are unused, but we may use them later
-    _tick_size_percent: u8,
-    _tick_size_min_in_lamports: u64,
-    _tick_size_max_in_lamports: u64,
+    tick_size_percent: u8,
+    tick_size_min_in_lamports: u64,
+    tick_size_max_in_lamports: u64,
 ) -> Result<()> {
     let owner = &ctx.accounts.owner;
     let authority = &ctx.accounts.authority;
@@ -53,23 +58,34 @@ pub fn handle_set_tick_size<'info>(
         &token_account.mint,
     )?;
 
-    if !owner.to_account_info().is_signer && !authority.to_account_info().is_signer {
-        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    if !owner.to_account_info().is_signer {
+        assert_authority_or_role(
+            auction_house,
+            &authority.to_account_info(),
+            ctx.remaining_accounts,
+            ctx.program_id,
+            AuctionHouseRole::ListingModerator,
+        )?;
     }
 
     if token_account.amount == 0 {
         return Err(AuctionHouseError::InvalidTokenAmount.into());
     }
 
-    if tick_size_constant_in_full_decimals != 0
-        && tick_size_constant_in_full_decimals
-            < get_min_price_difference_for_decimals(treasury_mint.decimals)
-    {
-        // We allow resetting to 0, because 0 means that the default tick size of 10% should be used.
-        return Err(AuctionHouseError::TickSizeTooLow.into());
-    }
+    assert_valid_tick_size(
+        &TickSize {
+            constant_in_lamports: tick_size_constant_in_full_decimals,
+            percent: tick_size_percent,
+            min_in_lamports: tick_size_min_in_lamports,
+            max_in_lamports: tick_size_max_in_lamports,
+        },
+        treasury_mint.decimals,
+    )?;
 
     last_bid_price.tick_size_constant_in_lamports = tick_size_constant_in_full_decimals;
+    last_bid_price.tick_size_percent = tick_size_percent;
+    last_bid_price.tick_size_min_in_lamports = tick_size_min_in_lamports;
+    last_bid_price.tick_size_max_in_lamports = tick_size_max_in_lamports;
 
     Ok(())
 }
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole,
+    RevenueSplitRecipient, TreasurySplit, TREASURY_SPLIT_SIZE, TREASURY_SPLIT_VERSION,
+};
+
+#[derive(Accounts)]
+pub struct SetTreasurySplit<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+    authority: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TREASURY_SPLIT_SIZE,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY_SPLIT.as_bytes()
+        ],
+        bump
+    )]
+    treasury_split: Account<'info, TreasurySplit>,
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Proposes the recipients which distribute_treasury splits the treasury between. They can
+ * be applied with apply_pending_treasury_split once TREASURY_SPLIT_DELAY_IN_SECONDS has
+ * passed. Passing no recipients proposes removing the split, which is delayed as well since
+ * it re-enables withdraw_from_treasury.
+ */
+pub fn handle_set_treasury_split(
+    ctx: Context<SetTreasurySplit>,
+    recipients: Vec<RevenueSplitRecipient>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let treasury_split = &mut ctx.accounts.treasury_split;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::TreasuryManager,
+    )?;
+    assert_valid_revenue_split_recipients(&recipients)?;
+
+    treasury_split.auction_house = auction_house.key();
+    treasury_split.pending_recipients = Some(recipients);
+    treasury_split.pending_recipients_effective_time = Clock::get()?
+        .unix_timestamp
+        .checked_add(TREASURY_SPLIT_DELAY_IN_SECONDS)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    treasury_split.bump = *ctx.bumps.get("treasury_split").unwrap();
+    treasury_split.version = TREASURY_SPLIT_VERSION;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, LastBidPrice, SharedEscrow};
+
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8)]
+pub struct SettleSharedEscrowReservation<'info> {
+    /// CHECK: No need to deserialize.
+    wallet: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    authority: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            SHARED_ESCROW.as_bytes()
+        ],
+        bump = shared_escrow.bump
+    )]
+    shared_escrow: Account<'info, SharedEscrow>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = escrow_payment_bump
+    )]
+    escrow_payment_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    token_mint: UncheckedAccount<'info>,
+    #[account(mut)]
+    last_bid_price: Account<'info, LastBidPrice>,
+}
+
+/**
+ * Moves the funds reserved for the winning bid from the bidder's shared escrow to their
+ * escrow payment account for this mint, so that execute_sale_v2 can pay for the NFT as
+ * usual. This is meant to be called in the same transaction as execute_sale_v2.
+ */
+pub fn handle_settle_shared_escrow_reservation(
+    ctx: Context<SettleSharedEscrowReservation>,
+    _escrow_payment_bump: u8,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let authority = &ctx.accounts.authority;
+    let auction_house = &ctx.accounts.auction_house;
+    let shared_escrow = &mut ctx.accounts.shared_escrow;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let last_bid_price = &mut ctx.accounts.last_bid_price;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+    assert_not_paused(auction_house)?;
+    assert_valid_last_bid_price(
+        &last_bid_price.to_account_info(),
+        ctx.program_id,
+        &token_mint.key(),
+    )?;
+
+    if !wallet.to_account_info().is_signer && !authority.to_account_info().is_signer {
+        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    }
+
+    if !last_bid_price.bidder_uses_shared_escrow || last_bid_price.bidder != Some(wallet.key())
+    {
+        return Err(AuctionHouseError::NoSharedEscrowReservation.into());
+    }
+
+    // The full max bid is moved for proxy bids, the unused part can be withdrawn after the sale.
+    let amount = get_escrowed_bid_amount(last_bid_price);
+    release_shared_escrow_amount(shared_escrow, amount)?;
+    last_bid_price.bidder_uses_shared_escrow = false;
+
+    let shared_escrow_info = shared_escrow.to_account_info();
+    **shared_escrow_info.lamports.borrow_mut() = shared_escrow_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    **escrow_payment_account.lamports.borrow_mut() = escrow_payment_account
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
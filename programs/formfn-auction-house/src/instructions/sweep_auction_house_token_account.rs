@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError};
+
+#[derive(Accounts)]
+pub struct SweepAuctionHouseTokenAccount<'info> {
+    authority: Signer<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump = auction_house.fee_payer_bump
+    )]
+    auction_house_fee_account: UncheckedAccount<'info>,
+    /// CHECK: No need to deserialize.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump = auction_house.treasury_bump
+    )]
+    auction_house_treasury: UncheckedAccount<'info>,
+    #[account(
+        has_one = authority,
+        has_one = auction_house_fee_account,
+        has_one = auction_house_treasury,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(mut)]
+    token_account: Box<Account<'info, TokenAccount>>,
+    /// Account to send the tokens to.
+    /// CHECK: Passed to token program
+    #[account(mut)]
+    token_receiver: UncheckedAccount<'info>,
+    /// Who receives the rent if the token account is closed.
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    rent_receiver: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * Sweeps any token out of a token account owned by the auction house fee account or
+ * treasury PDA. Token accounts owned by the auction house itself (e.g. the treasury for
+ * non-native auction houses and buyer escrows) cannot be swept, and neither can treasury
+ * mint token accounts of the treasury PDA while the treasury is split.
+ */
+pub fn handle_sweep_auction_house_token_account(
+    ctx: Context<SweepAuctionHouseTokenAccount>,
+    close_token_account: bool,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let token_account = &ctx.accounts.token_account;
+    let token_receiver = &ctx.accounts.token_receiver;
+    let rent_receiver = &ctx.accounts.rent_receiver;
+    let token_program = &ctx.accounts.token_program;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    let auction_house_key = auction_house.key();
+    let fee_payer_bump = [auction_house.fee_payer_bump];
+    let treasury_bump = [auction_house.treasury_bump];
+    let (token_account_owner, seeds) = if token_account.owner == auction_house_fee_account.key() {
+        (
+            auction_house_fee_account.to_account_info(),
+            [
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                FEE_PAYER.as_bytes(),
+                &fee_payer_bump,
+            ],
+        )
+    } else if token_account.owner == auction_house_treasury.key() {
+        if auction_house.has_treasury_split && token_account.mint == auction_house.treasury_mint {
+            return Err(AuctionHouseError::TreasuryIsSplit.into());
+        }
+        (
+            auction_house_treasury.to_account_info(),
+            [
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                TREASURY.as_bytes(),
+                &treasury_bump,
+            ],
+        )
+    } else {
+        return Err(AuctionHouseError::InvalidSweepTokenAccountOwner.into());
+    };
+
+    sweep_token_account(
+        token_account,
+        token_receiver.to_account_info(),
+        rent_receiver.to_account_info(),
+        token_account_owner,
+        token_program.to_account_info(),
+        &seeds,
+        close_token_account,
+    )
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, EditionDistributor};
+
+#[derive(Accounts)]
+pub struct SweepEditionDistributorTokenAccount<'info> {
+    /// CHECK: No need to deserialize
+    #[account()]
+    master_edition_mint: UncheckedAccount<'info>,
+    #[account(
+        has_one = owner,
+        has_one = master_edition_mint,
+        seeds = [
+            EDITION_DISTRIBUTOR.as_bytes(),
+            master_edition_mint.key().as_ref()
+        ],
+        bump = edition_distributor.bump
+    )]
+    edition_distributor: Box<Account<'info, EditionDistributor>>,
+    owner: Signer<'info>,
+    authority: Signer<'info>,
+    #[account(
+        has_one = authority,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        constraint = edition_distributor_token_account.owner == edition_distributor.key()
+    )]
+    edition_distributor_token_account: Box<Account<'info, TokenAccount>>,
+    /// Account to send the tokens to.
+    /// CHECK: Passed to token program
+    #[account(mut)]
+    token_receiver: UncheckedAccount<'info>,
+    /// Who receives the rent if the token account is closed.
+    /// CHECK: No need to deserialize.
+    #[account(mut)]
+    rent_receiver: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+}
+
+/**
+ * Sweeps any token (e.g. airdrops or tokens sent by mistake) out of a token account owned
+ * by an EditionDistributor. Both the auction house authority and the distributor owner
+ * must sign. The master edition token cannot be swept.
+ */
+pub fn handle_sweep_edition_distributor_token_account(
+    ctx: Context<SweepEditionDistributorTokenAccount>,
+    close_token_account: bool,
+) -> Result<()> {
+    let edition_distributor = &ctx.accounts.edition_distributor;
+    let edition_distributor_token_account = &ctx.accounts.edition_distributor_token_account;
+    let token_receiver = &ctx.accounts.token_receiver;
+    let rent_receiver = &ctx.accounts.rent_receiver;
+    let token_program = &ctx.accounts.token_program;
+    let auction_house = &ctx.accounts.auction_house;
+
+    assert_valid_auction_house(ctx.program_id, &auction_house.key())?;
+
+    if edition_distributor_token_account.mint == edition_distributor.master_edition_mint {
+        return Err(AuctionHouseError::CannotSweepMasterEditionMint.into());
+    }
+
+    let seeds = [
+        EDITION_DISTRIBUTOR.as_bytes(),
+        &edition_distributor.master_edition_mint.to_bytes(),
+        &[edition_distributor.bump],
+    ];
+
+    sweep_token_account(
+        edition_distributor_token_account,
+        token_receiver.to_account_info(),
+        rent_receiver.to_account_info(),
+        edition_distributor.to_account_info(),
+        token_program.to_account_info(),
+        &seeds,
+        close_token_account,
+    )
+}
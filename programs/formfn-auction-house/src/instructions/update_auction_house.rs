@@ -39,6 +39,10 @@ pub struct UpdateAuctionHouse<'info> {
     rent: Sysvar<'info, Rent>,
 }
 
+/**
+ * Updates the auction house settings. Changes to the authority and withdrawal destinations
+ * and fee increases don't take effect immediately, see below.
+ */
 pub fn handle_update_auction_house(
     ctx: Context<UpdateAuctionHouse>,
     seller_fee_basis_points: Option<u16>,
@@ -60,12 +64,21 @@ pub fn handle_update_auction_house(
     let rent = &ctx.accounts.rent;
     let is_native = treasury_mint.key() == spl_token::native_mint::id();
 
+    // Fee decreases take effect immediately, and increases are pending until
+    // FEE_INCREASE_DELAY_IN_SECONDS has passed, see apply_pending_fees.
+    let mut has_fee_increase = false;
     if let Some(sfbp) = seller_fee_basis_points {
         if sfbp > 10000 {
             return Err(AuctionHouseError::InvalidBasisPoints.into());
         }
 
-        auction_house.seller_fee_basis_points = sfbp;
+        if sfbp > auction_house.seller_fee_basis_points {
+            auction_house.pending_seller_fee_basis_points = Some(sfbp);
+            has_fee_increase = true;
+        } else {
+            auction_house.seller_fee_basis_points = sfbp;
+            auction_house.pending_seller_fee_basis_points = None;
+        }
     }
 
     if let Some(sfbp) = seller_fee_basis_points_secondary {
@@ -73,7 +86,20 @@ pub fn handle_update_auction_house(
             return Err(AuctionHouseError::InvalidBasisPoints.into());
         }
 
-        auction_house.seller_fee_basis_points_secondary = sfbp;
+        if sfbp > auction_house.seller_fee_basis_points_secondary {
+            auction_house.pending_seller_fee_basis_points_secondary = Some(sfbp);
+            has_fee_increase = true;
+        } else {
+            auction_house.seller_fee_basis_points_secondary = sfbp;
+            auction_house.pending_seller_fee_basis_points_secondary = None;
+        }
+    }
+
+    if has_fee_increase {
+        auction_house.pending_fees_effective_time = Clock::get()?
+            .unix_timestamp
+            .checked_add(FEE_INCREASE_DELAY_IN_SECONDS)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
     }
 
     if let Some(rqf) = requires_sign_off {
@@ -86,9 +112,19 @@ pub fn handle_update_auction_house(
         auction_house.pay_all_fees = pay_all_fees_val;
     }
 
-    auction_house.authority = new_authority.key();
-    auction_house.treasury_withdrawal_destination = treasury_withdrawal_destination.key();
-    auction_house.fee_withdrawal_destination = fee_withdrawal_destination.key();
+    // The new authority and withdrawal destinations must accept the change, see
+    // accept_authority, accept_treasury_withdrawal_destination and
+    // accept_fee_withdrawal_destination.
+    auction_house.pending_authority =
+        get_pending_key(&auction_house.authority, &new_authority.key());
+    auction_house.pending_treasury_withdrawal_destination = get_pending_key(
+        &auction_house.treasury_withdrawal_destination,
+        &treasury_withdrawal_destination.key(),
+    );
+    auction_house.pending_fee_withdrawal_destination = get_pending_key(
+        &auction_house.fee_withdrawal_destination,
+        &fee_withdrawal_destination.key(),
+    );
 
     if !is_native {
         if treasury_withdrawal_destination.data_is_empty() {
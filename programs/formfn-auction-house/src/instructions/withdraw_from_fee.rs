@@ -3,7 +3,7 @@ use anchor_lang::{
     solana_program::{program::invoke_signed, system_instruction},
 };
 
-use crate::{constants::*, AuctionHouse};
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseRole};
 
 #[derive(Accounts)]
 pub struct WithdrawFromFee<'info> {
@@ -24,7 +24,6 @@ pub struct WithdrawFromFee<'info> {
     auction_house_fee_account: UncheckedAccount<'info>,
     #[account(
         mut,
-        has_one = authority,
         has_one = fee_withdrawal_destination,
         has_one = auction_house_fee_account,
         seeds = [
@@ -44,6 +43,14 @@ pub fn handle_withdraw_from_fee(ctx: Context<WithdrawFromFee>, amount: u64) -> R
     let auction_house = &ctx.accounts.auction_house;
     let system_program = &ctx.accounts.system_program;
 
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::FeeManager,
+    )?;
+
     let auction_house_key = auction_house.key();
     let seeds = [
         PREFIX.as_bytes(),
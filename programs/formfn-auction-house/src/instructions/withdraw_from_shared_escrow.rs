@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, SharedEscrow};
+
+#[derive(Accounts)]
+pub struct WithdrawFromSharedEscrow<'info> {
+    #[account(mut)]
+    wallet: Signer<'info>,
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump = auction_house.bump,
+    )]
+    auction_house: Account<'info, AuctionHouse>,
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = auction_house,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            SHARED_ESCROW.as_bytes()
+        ],
+        bump = shared_escrow.bump
+    )]
+    shared_escrow: Account<'info, SharedEscrow>,
+}
+
+/**
+ * Withdraws unreserved funds from the wallet's shared escrow. Funds reserved for the
+ * wallet's current highest bids cannot be withdrawn until those bids are outbid.
+ */
+pub fn handle_withdraw_from_shared_escrow(
+    ctx: Context<WithdrawFromSharedEscrow>,
+    amount: u64,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let shared_escrow = &ctx.accounts.shared_escrow;
+
+    let shared_escrow_info = shared_escrow.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(shared_escrow_info.data_len());
+    let available_balance = get_shared_escrow_available_balance(
+        shared_escrow_info.lamports(),
+        rent_exempt_minimum,
+        shared_escrow.reserved_amount,
+    );
+    if amount > available_balance {
+        return Err(AuctionHouseError::InsufficientSharedEscrowBalance.into());
+    }
+
+    **shared_escrow_info.lamports.borrow_mut() = shared_escrow_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    **wallet.lamports.borrow_mut() = wallet
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anchor_lang::{
 };
 use anchor_spl::token::{Mint, Token};
 
-use crate::{constants::*, AuctionHouse};
+use crate::{constants::*, utils::*, AuctionHouse, AuctionHouseError, AuctionHouseRole};
 
 #[derive(Accounts)]
 pub struct WithdrawFromTreasury<'info> {
@@ -26,7 +26,6 @@ pub struct WithdrawFromTreasury<'info> {
     auction_house_treasury: UncheckedAccount<'info>,
     #[account(
         mut,
-        has_one = authority,
         has_one = treasury_mint,
         has_one = treasury_withdrawal_destination,
         has_one = auction_house_treasury,
@@ -42,6 +41,10 @@ pub struct WithdrawFromTreasury<'info> {
     system_program: Program<'info, System>,
 }
 
+/**
+ * Withdraws from the treasury to treasury_withdrawal_destination. Disabled while the
+ * treasury has a TreasurySplit, which pays the treasury out with distribute_treasury instead.
+ */
 pub fn handle_withdraw_from_treasury(
     ctx: Context<WithdrawFromTreasury>,
     amount: u64,
@@ -53,6 +56,18 @@ pub fn handle_withdraw_from_treasury(
     let token_program = &ctx.accounts.token_program;
     let system_program = &ctx.accounts.system_program;
 
+    assert_authority_or_role(
+        auction_house,
+        &ctx.accounts.authority.to_account_info(),
+        ctx.remaining_accounts,
+        ctx.program_id,
+        AuctionHouseRole::TreasuryManager,
+    )?;
+
+    if auction_house.has_treasury_split {
+        return Err(AuctionHouseError::TreasuryIsSplit.into());
+    }
+
     let is_native = treasury_mint.key() == spl_token::native_mint::id();
     let auction_house_seeds = [
         PREFIX.as_bytes(),
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod tests;
@@ -9,6 +10,7 @@ pub mod utils;
 
 pub use constants::*;
 pub use errors::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -205,7 +207,6 @@ pub mod auction_house {
     pub fn set_tick_size<'info>(
         ctx: Context<'_, '_, '_, 'info, SetTickSize<'info>>,
         tick_size_constant_in_full_decimals: u64,
-        // These are unused, but we may use them later
         tick_size_percent: u8,
         tick_size_min_in_lamports: u64,
         tick_size_max_in_lamports: u64,
@@ -357,4 +358,404 @@ pub mod auction_house {
     ) -> Result<()> {
         handle_close_edition_distributor(ctx)
     }
+
+    pub fn set_edition_distributor_max_editions_to_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetEditionDistributorMaxEditionsToSell<'info>>,
+        edition_bump: u8,
+        max_editions_to_sell: Option<u64>,
+    ) -> Result<()> {
+        handle_set_edition_distributor_max_editions_to_sell(ctx, edition_bump, max_editions_to_sell)
+    }
+
+    pub fn lock_edition_distributor_supply<'info>(
+        ctx: Context<'_, '_, '_, 'info, LockEditionDistributorSupply<'info>>,
+        edition_bump: u8,
+    ) -> Result<()> {
+        handle_lock_edition_distributor_supply(ctx, edition_bump)
+    }
+
+    pub fn mint_reserved_edition<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintReservedEdition<'info>>,
+        edition_bump: u8,
+        recipients: Vec<Pubkey>,
+    ) -> Result<()> {
+        handle_mint_reserved_edition(ctx, edition_bump, recipients)
+    }
+
+    pub fn buy_edition_for_recipient<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyEditionForRecipient<'info>>,
+        edition_bump: u8,
+        // The price the buyer pays. Validated inside this instruction
+        price_in_lamports: u64,
+        recipient_edition_info_account_bump: u8,
+        recipient_merkle_allowlist_proof_data: Option<BuyerMerkleAllowlistProofData>,
+    ) -> Result<()> {
+        handle_buy_edition_for_recipient(
+            ctx,
+            edition_bump,
+            price_in_lamports,
+            recipient_edition_info_account_bump,
+            recipient_merkle_allowlist_proof_data,
+        )
+    }
+
+    pub fn set_edition_distributor_bot_tax<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetEditionDistributorBotTax<'info>>,
+        bot_tax_amount: Option<u64>,
+        bot_tax_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        handle_set_edition_distributor_bot_tax(ctx, bot_tax_amount, bot_tax_recipient)
+    }
+
+    pub fn buy_edition_v3<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyEditionV3<'info>>,
+        edition_bump: u8,
+        // The price the buyer pays. Validated inside this instruction
+        price_in_lamports: u64,
+        recipient_edition_info_account_bump: u8,
+        recipient_merkle_allowlist_proof_data: Option<BuyerMerkleAllowlistProofData>,
+    ) -> Result<()> {
+        handle_buy_edition_v3(
+            ctx,
+            edition_bump,
+            price_in_lamports,
+            recipient_edition_info_account_bump,
+            recipient_merkle_allowlist_proof_data,
+        )
+    }
+
+    pub fn sweep_edition_distributor_token_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepEditionDistributorTokenAccount<'info>>,
+        close_token_account: bool,
+    ) -> Result<()> {
+        handle_sweep_edition_distributor_token_account(ctx, close_token_account)
+    }
+
+    pub fn sweep_auction_house_token_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepAuctionHouseTokenAccount<'info>>,
+        close_token_account: bool,
+    ) -> Result<()> {
+        handle_sweep_auction_house_token_account(ctx, close_token_account)
+    }
+
+    pub fn close_last_bid_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseLastBidPrice<'info>>,
+        program_as_signer_bump: u8,
+    ) -> Result<()> {
+        handle_close_last_bid_price(ctx, program_as_signer_bump)
+    }
+
+    pub fn migrate_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateAccount<'info>>,
+        account_type: MigratableAccountType,
+    ) -> Result<()> {
+        handle_migrate_account(ctx, account_type)
+    }
+
+    pub fn create_trade_state_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTradeState<'info>>,
+        trade_state_bump: u8,
+        price: u64,
+        token_size: u64,
+        sale_type: u8,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        handle_create_trade_state_v2(ctx, trade_state_bump, price, token_size, sale_type, expiry)
+    }
+
+    pub fn migrate_legacy_trade_state<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateLegacyTradeState<'info>>,
+        trade_state_bump: u8,
+        price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        handle_migrate_legacy_trade_state(ctx, trade_state_bump, price, token_size)
+    }
+
+    pub fn deposit_to_shared_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositToSharedEscrow<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_deposit_to_shared_escrow(ctx, amount)
+    }
+
+    pub fn withdraw_from_shared_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawFromSharedEscrow<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_withdraw_from_shared_escrow(ctx, amount)
+    }
+
+    pub fn buy_v2_with_shared_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyV2WithSharedEscrow<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        // Unix time (seconds since epoch)
+        auction_end_time: Option<i64>,
+        previous_bidder_escrow_payment_bump: u8,
+    ) -> Result<()> {
+        handle_buy_v2_with_shared_escrow(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+            auction_end_time,
+            previous_bidder_escrow_payment_bump,
+        )
+    }
+
+    pub fn settle_shared_escrow_reservation<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleSharedEscrowReservation<'info>>,
+        escrow_payment_bump: u8,
+    ) -> Result<()> {
+        handle_settle_shared_escrow_reservation(ctx, escrow_payment_bump)
+    }
+
+    pub fn release_shared_escrow_reservation<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseSharedEscrowReservation<'info>>,
+    ) -> Result<()> {
+        handle_release_shared_escrow_reservation(ctx)
+    }
+
+    pub fn set_buy_now_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBuyNowPrice<'info>>,
+        buy_now_price: Option<u64>,
+    ) -> Result<()> {
+        handle_set_buy_now_price(ctx, buy_now_price)
+    }
+
+    pub fn buy_now<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyNow<'info>>,
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buy_now_price: u64,
+        seller_price: u64,
+        token_size: u64,
+        previous_bidder_escrow_payment_bump: u8,
+    ) -> Result<()> {
+        handle_buy_now(
+            ctx,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buy_now_price,
+            seller_price,
+            token_size,
+            previous_bidder_escrow_payment_bump,
+        )
+    }
+
+    pub fn create_sealed_bid_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSealedBidAuction<'info>>,
+        reserve_price: u64,
+        commit_end_time: i64,
+        reveal_end_time: i64,
+        is_second_price: bool,
+    ) -> Result<()> {
+        handle_create_sealed_bid_auction(
+            ctx,
+            reserve_price,
+            commit_end_time,
+            reveal_end_time,
+            is_second_price,
+        )
+    }
+
+    pub fn commit_sealed_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, CommitSealedBid<'info>>,
+        escrow_payment_bump: u8,
+        commitment: [u8; 32],
+        deposit: u64,
+    ) -> Result<()> {
+        handle_commit_sealed_bid(ctx, escrow_payment_bump, commitment, deposit)
+    }
+
+    pub fn reveal_sealed_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealSealedBid<'info>>,
+        price: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        handle_reveal_sealed_bid(ctx, price, salt)
+    }
+
+    pub fn refund_sealed_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundSealedBid<'info>>,
+    ) -> Result<()> {
+        handle_refund_sealed_bid(ctx)
+    }
+
+    pub fn close_sealed_bid_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseSealedBidAuction<'info>>,
+    ) -> Result<()> {
+        handle_close_sealed_bid_auction(ctx)
+    }
+
+    pub fn buy_v2_with_max_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyV2WithMaxBid<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        // Unix time (seconds since epoch)
+        auction_end_time: Option<i64>,
+        previous_bidder_escrow_payment_bump: u8,
+        bid_price: u64,
+    ) -> Result<()> {
+        handle_buy_v2_with_max_bid(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+            auction_end_time,
+            previous_bidder_escrow_payment_bump,
+            bid_price,
+        )
+    }
+
+    pub fn set_default_tick_size<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetDefaultTickSize<'info>>,
+        default_tick_size: TickSize,
+    ) -> Result<()> {
+        handle_set_default_tick_size(ctx, default_tick_size)
+    }
+
+    pub fn set_claimable_balances_enabled<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetClaimableBalancesEnabled<'info>>,
+        claimable_balances_enabled: bool,
+    ) -> Result<()> {
+        handle_set_claimable_balances_enabled(ctx, claimable_balances_enabled)
+    }
+
+    pub fn claim_balance<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimBalance<'info>>,
+    ) -> Result<()> {
+        handle_claim_balance(ctx)
+    }
+
+    pub fn set_revenue_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRevenueSplit<'info>>,
+        recipients: Vec<RevenueSplitRecipient>,
+    ) -> Result<()> {
+        handle_set_revenue_split(ctx, recipients)
+    }
+
+    pub fn set_edition_distributor_revenue_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetEditionDistributorRevenueSplit<'info>>,
+        recipients: Vec<RevenueSplitRecipient>,
+    ) -> Result<()> {
+        handle_set_edition_distributor_revenue_split(ctx, recipients)
+    }
+
+    pub fn set_buyer_premium_basis_points<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBuyerPremiumBasisPoints<'info>>,
+        buyer_premium_basis_points: u16,
+    ) -> Result<()> {
+        handle_set_buyer_premium_basis_points(ctx, buyer_premium_basis_points)
+    }
+
+    pub fn set_fee_override<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetFeeOverride<'info>>,
+        key: Pubkey,
+        seller_fee_basis_points: u16,
+        seller_fee_basis_points_secondary: u16,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        handle_set_fee_override(
+            ctx,
+            key,
+            seller_fee_basis_points,
+            seller_fee_basis_points_secondary,
+            expiry,
+        )
+    }
+
+    pub fn close_fee_override<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseFeeOverride<'info>>,
+    ) -> Result<()> {
+        handle_close_fee_override(ctx)
+    }
+
+    pub fn set_treasury_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetTreasurySplit<'info>>,
+        recipients: Vec<RevenueSplitRecipient>,
+    ) -> Result<()> {
+        handle_set_treasury_split(ctx, recipients)
+    }
+
+    pub fn distribute_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeTreasury<'info>>,
+    ) -> Result<()> {
+        handle_distribute_treasury(ctx)
+    }
+
+    pub fn accept_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptAuthority<'info>>,
+    ) -> Result<()> {
+        handle_accept_authority(ctx)
+    }
+
+    pub fn accept_treasury_withdrawal_destination<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptTreasuryWithdrawalDestination<'info>>,
+    ) -> Result<()> {
+        handle_accept_treasury_withdrawal_destination(ctx)
+    }
+
+    pub fn accept_fee_withdrawal_destination<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptFeeWithdrawalDestination<'info>>,
+    ) -> Result<()> {
+        handle_accept_fee_withdrawal_destination(ctx)
+    }
+
+    pub fn apply_pending_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyPendingFees<'info>>,
+    ) -> Result<()> {
+        handle_apply_pending_fees(ctx)
+    }
+
+    pub fn apply_pending_treasury_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApplyPendingTreasurySplit<'info>>,
+    ) -> Result<()> {
+        handle_apply_pending_treasury_split(ctx)
+    }
+
+    pub fn set_guardian<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetGuardian<'info>>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        handle_set_guardian(ctx, guardian)
+    }
+
+    pub fn set_paused<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetPaused<'info>>,
+        paused: bool,
+    ) -> Result<()> {
+        handle_set_paused(ctx, paused)
+    }
+
+    pub fn set_auction_house_roles<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetAuctionHouseRoles<'info>>,
+        fee_manager: Pubkey,
+        treasury_manager: Pubkey,
+        listing_moderator: Pubkey,
+        drop_manager: Pubkey,
+    ) -> Result<()> {
+        handle_set_auction_house_roles(
+            ctx,
+            fee_manager,
+            treasury_manager,
+            listing_moderator,
+            drop_manager,
+        )
+    }
+
+    pub fn create_last_bid_price_audit_log<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateLastBidPriceAuditLog<'info>>,
+    ) -> Result<()> {
+        handle_create_last_bid_price_audit_log(ctx)
+    }
 }
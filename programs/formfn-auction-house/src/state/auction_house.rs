@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{TickSize, TICK_SIZE_SIZE};
+
 #[account]
 pub struct AuctionHouse {
     pub auction_house_fee_account: Pubkey,
@@ -18,10 +20,42 @@ pub struct AuctionHouse {
     pub seller_fee_basis_points_secondary: u16,
 
     // New
-    // TODO[@arcticmatt]: Is this field account for in AUCTION_HOUSE_SIZE?
     pub pay_all_fees: bool,
+    // Layout version of this account, see AUCTION_HOUSE_VERSION. 0 for accounts created
+    // before versioning was added.
+    pub version: u8,
+    // Tick size for auctions which don't set their own with set_tick_size, see
+    // set_default_tick_size.
+    pub default_tick_size: TickSize,
+    // If true, execute_sale_v2 credits creators and sellers' ClaimableBalance accounts instead
+    // of paying them directly, see set_claimable_balances_enabled.
+    pub claimable_balances_enabled: bool,
+    // Fee charged to the winner of an auction on top of the winning bid and paid to the
+    // treasury, see set_buyer_premium_basis_points.
+    pub buyer_premium_basis_points: u16,
+    // Changes proposed by update_auction_house which take effect once accepted by the new
+    // authority or withdrawal destination owner. None if no change is pending.
+    pub pending_authority: Option<Pubkey>,
+    pub pending_treasury_withdrawal_destination: Option<Pubkey>,
+    pub pending_fee_withdrawal_destination: Option<Pubkey>,
+    // Fee increases proposed by update_auction_house, which can be applied with
+    // apply_pending_fees once pending_fees_effective_time has passed.
+    pub pending_seller_fee_basis_points: Option<u16>,
+    pub pending_seller_fee_basis_points_secondary: Option<u16>,
+    pub pending_fees_effective_time: i64,
+    // If true, sell, buy_v2, execute_sale_v2 and buy_edition_v2 are disabled while users can
+    // still exit with cancel_v2, withdraw and thaw_delegated_account, see set_paused.
+    pub paused: bool,
+    // Can pause and unpause the auction house next to the authority. The default pubkey if
+    // there is no guardian, see set_guardian.
+    pub guardian: Pubkey,
+    // Whether the auction house's TreasurySplit has recipients, in which case the treasury
+    // can only be paid out with distribute_treasury, see apply_pending_treasury_split.
+    pub has_treasury_split: bool,
 }
 
+pub const AUCTION_HOUSE_VERSION: u8 = 1;
+
 pub const AUCTION_HOUSE_SIZE: usize = 8 + //key
 32 + //fee payer
 32 + //treasury
@@ -37,4 +71,20 @@ pub const AUCTION_HOUSE_SIZE: usize = 8 + //key
 1 + // requires sign off
 1 + // can change sale price
 2 + // seller fee basis points secondary
-220; // padding
+1 + // pay_all_fees
+1 + // version
+TICK_SIZE_SIZE + // default_tick_size
+1 + // claimable_balances_enabled
+2 + // buyer_premium_basis_points
+33 + // pending_authority
+33 + // pending_treasury_withdrawal_destination
+33 + // pending_fee_withdrawal_destination
+3 + // pending_seller_fee_basis_points
+3 + // pending_seller_fee_basis_points_secondary
+8 + // pending_fees_effective_time
+1 + // paused
+32 + // guardian
+1 + // has_treasury_split
+AUCTION_HOUSE_PADDING;
+
+pub const AUCTION_HOUSE_PADDING: usize = 43;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+// Keys which can sign admin instructions next to the auction house authority, so operational
+// keys can be handed to services without giving them access to everything else. A role is
+// unassigned if its key is the default pubkey. The guardian role is AuctionHouse.guardian,
+// see set_guardian. Admin instructions find this account in remaining_accounts, see
+// assert_authority_or_role.
+#[account]
+#[derive(Default)]
+pub struct AuctionHouseRoles {
+    pub auction_house: Pubkey,
+    // Can withdraw from the fee account and manage fee overrides.
+    pub fee_manager: Pubkey,
+    // Can withdraw from the treasury to treasury_withdrawal_destination and set the treasury
+    // split.
+    pub treasury_manager: Pubkey,
+    // Can update listings, e.g. with set_last_bid_price and set_has_been_sold.
+    pub listing_moderator: Pubkey,
+    // Can manage edition distributors and their allowlists.
+    pub drop_manager: Pubkey,
+    pub bump: u8,
+    // Layout version of this account, see AUCTION_HOUSE_ROLES_VERSION.
+    pub version: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuctionHouseRole {
+    FeeManager,
+    TreasuryManager,
+    ListingModerator,
+    DropManager,
+}
+
+pub const AUCTION_HOUSE_ROLES_VERSION: u8 = 1;
+
+pub const AUCTION_HOUSE_ROLES_SIZE: usize = 8 + // Discriminator
+32 + // auction_house
+32 + // fee_manager
+32 + // treasury_manager
+32 + // listing_moderator
+32 + // drop_manager
+1 + // bump
+1 + // version
+AUCTION_HOUSE_ROLES_PADDING;
+
+pub const AUCTION_HOUSE_ROLES_PADDING: usize = 128;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+// Sale proceeds owed to a creator or seller by sales on an auction house with
+// claimable_balances_enabled. The lamports above the rent exempt minimum, or for SPL auction
+// houses the tokens in this account's associated token account, are held for the recipient
+// until they withdraw them with claim_balance.
+#[account]
+#[derive(Default)]
+pub struct ClaimableBalance {
+    pub auction_house: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+    // Layout version of this account, see CLAIMABLE_BALANCE_VERSION.
+    pub version: u8,
+}
+
+pub const CLAIMABLE_BALANCE_VERSION: u8 = 1;
+
+pub const CLAIMABLE_BALANCE_SIZE: usize = 8 + // Discriminator
+32 + // auction_house
+32 + // recipient
+8 + // amount
+1 + // bump
+1 + // version
+CLAIMABLE_BALANCE_PADDING;
+
+pub const CLAIMABLE_BALANCE_PADDING: usize = 64;
@@ -4,10 +4,16 @@ use anchor_lang::prelude::*;
 #[account]
 pub struct EditionAllowlistSettings {
     pub bump: u8,
+    // Layout version of this account, see EDITION_ALLOWLIST_SETTINGS_VERSION. Accounts created
+    // before versioning was added don't have this field, and must be migrated with
+    // migrate_account before use, see LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE.
+    pub version: u8,
     // Vector of merkle tree root hashes for edition allowlist.
     pub merkle_roots: Vec<[u8; 32]>,
 }
 
+pub const EDITION_ALLOWLIST_SETTINGS_VERSION: u8 = 1;
+
 pub const MERKLE_ROOT_SIZE: usize = 32;
 
 // Max allowlist size is 25,000 addresses. See also MerkleTreeLeafCountLimit.ts.
@@ -18,6 +24,18 @@ pub const MERKLE_ALLOWLIST_ROOT_LIST_SIZE: usize =
 
 pub const EDITION_ALLOWLIST_SETTINGS_SIZE: usize = 8 + // Discriminator
 1 + // bump
+1 + // version
 4 + // extra overhead for vector storage
 MERKLE_ALLOWLIST_ROOT_LIST_SIZE + // list of merkle_roots
-128; // padding
+EDITION_ALLOWLIST_SETTINGS_PADDING;
+
+pub const EDITION_ALLOWLIST_SETTINGS_PADDING: usize = 128;
+
+// Size of accounts created before version was added. Since version comes before the
+// variable length merkle_roots, these accounts are one byte smaller, which is how
+// migrate_account tells them apart.
+pub const LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE: usize = EDITION_ALLOWLIST_SETTINGS_SIZE - 1;
+
+// Offset of version, and of the bytes migrate_account shifts to make room for it.
+pub const EDITION_ALLOWLIST_SETTINGS_VERSION_OFFSET: usize = 8 + // Discriminator
+1; // bump
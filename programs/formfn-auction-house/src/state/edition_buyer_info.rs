@@ -7,9 +7,17 @@ pub struct EditionBuyerInfoAccount {
     pub number_bought: u16,
     // Number bought in for an edition allowlist sale.
     pub number_bought_allowlist: u16,
+    // Layout version of this account, see EDITION_BUYER_INFO_ACCOUNT_VERSION. 0 for accounts
+    // created before versioning was added.
+    pub version: u8,
 }
 
+pub const EDITION_BUYER_INFO_ACCOUNT_VERSION: u8 = 1;
+
 pub const EDITION_BUYER_INFO_ACCOUNT_SPACE: usize = 8 + // Discriminator
 2 + // number_bought
 2 + // number_bought_allowlist
-62; // padding
+1 + // version
+EDITION_BUYER_INFO_ACCOUNT_PADDING;
+
+pub const EDITION_BUYER_INFO_ACCOUNT_PADDING: usize = 61;
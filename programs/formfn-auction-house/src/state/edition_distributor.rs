@@ -25,8 +25,38 @@ pub struct EditionDistributor {
     pub allowlist_sale_price: Option<u64>,
     // The number of sales which occur via allowlist. This is relevant for public sale price calculations.
     pub allowlist_number_sold: u64,
+    // Optional cap on the number of editions which can be minted via this distributor, independent
+    // of the master edition max_supply. None if uncapped.
+    pub max_editions_to_sell: Option<u64>,
+    // Once the supply is locked no more editions can be minted via this distributor, and the
+    // master edition token can no longer be withdrawn from it.
+    pub is_supply_locked: bool,
+    // The number of editions minted by the owner via mint_reserved_edition. These do not count
+    // towards the edition number used for price calculations.
+    pub reserved_number_minted: u64,
+    // Amount taken from suspected bots, denominated in the treasury_mint. Defaults to 0.01 SOL
+    // if None.
+    pub bot_tax_amount: Option<u64>,
+    // Receives the bot tax, defaults to the auction house treasury if None. This is a token
+    // account for the treasury_mint if the distributor is non-native.
+    pub bot_tax_recipient: Option<Pubkey>,
+    // Running sale totals, updated by every edition purchase. Amounts are denominated in the
+    // treasury_mint. Reserved editions are not included. Sales which happened before these
+    // fields were added are not included either.
+    pub total_number_sold: u64,
+    pub total_gross_revenue: u64,
+    pub total_platform_fees: u64,
+    pub total_creator_proceeds: u64,
+    // Layout version of this account, see EDITION_DISTRIBUTOR_VERSION. 0 for accounts created
+    // before versioning was added.
+    pub version: u8,
+    // Whether sale proceeds go to the master edition mint's RevenueSplit, see
+    // set_edition_distributor_revenue_split.
+    pub has_revenue_split: bool,
 }
 
+pub const EDITION_DISTRIBUTOR_VERSION: u8 = 1;
+
 pub const EDITION_DISTRIBUTOR_SIZE: usize = 8 + // Discriminator
 1 + // bump
 32 + // master_edition_mint
@@ -40,4 +70,17 @@ PRICE_FUNCTION_SIZE + // price_function
 9 + // allowlist_sale_start_time
 9 + // allowlist_sale_price
 8 +// allowlist_number_sold
-191; // padding
+9 + // max_editions_to_sell
+1 + // is_supply_locked
+8 + // reserved_number_minted
+9 + // bot_tax_amount
+33 + // bot_tax_recipient
+8 + // total_number_sold
+8 + // total_gross_revenue
+8 + // total_platform_fees
+8 + // total_creator_proceeds
+1 + // version
+1 + // has_revenue_split
+EDITION_DISTRIBUTOR_PADDING;
+
+pub const EDITION_DISTRIBUTOR_PADDING: usize = 97;
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+// Platform fees which replace the auction house's seller_fee_basis_points and
+// seller_fee_basis_points_secondary for a mint (or the master edition mint of an edition
+// distributor), or for every mint with a given verified creator. It is passed in
+// remaining_accounts, and a mint override takes precedence over a creator override, see
+// get_fee_override_from_remaining_accounts.
+#[account]
+#[derive(Default)]
+pub struct FeeOverride {
+    pub auction_house: Pubkey,
+    // The mint or creator this override applies to.
+    pub key: Pubkey,
+    pub seller_fee_basis_points: u16,
+    pub seller_fee_basis_points_secondary: u16,
+    // Unix timestamp after which the override no longer applies. None if it doesn't expire.
+    pub expiry: Option<i64>,
+    pub bump: u8,
+    // Layout version of this account, see FEE_OVERRIDE_VERSION.
+    pub version: u8,
+}
+
+pub const FEE_OVERRIDE_VERSION: u8 = 1;
+
+pub const FEE_OVERRIDE_SIZE: usize = 8 + // Discriminator
+32 + // auction_house
+32 + // key
+2 + // seller_fee_basis_points
+2 + // seller_fee_basis_points_secondary
+9 + // expiry
+1 + // bump
+1 + // version
+FEE_OVERRIDE_PADDING;
+
+pub const FEE_OVERRIDE_PADDING: usize = 64;
@@ -9,12 +9,55 @@ pub struct LastBidPrice {
     pub has_been_sold: u8,
     pub tick_size_constant_in_lamports: u64,
     pub has_campaign_escrow_treasury: bool,
+    // Who paid the rent for this account, and receives it back when the account is closed.
+    // None for accounts created before this was tracked.
+    pub rent_payer: Option<Pubkey>,
+    // Layout version of this account, see LAST_BID_PRICE_VERSION. 0 for accounts created
+    // before versioning was added.
+    pub version: u8,
+    // Whether the current bid is backed by a reservation on the bidder's SharedEscrow,
+    // instead of the bidder's escrow payment account for this mint.
+    pub bidder_uses_shared_escrow: bool,
+    // Optional price at which a buyer can win the auction immediately, see
+    // set_buy_now_price. It no longer applies once the highest bid reaches it.
+    pub buy_now_price: Option<u64>,
+    // Max bid of the current bidder if they placed a proxy bid with buy_v2_with_max_bid, which
+    // is escrowed in full. price stays the visible price. 0 if the current bid is not a proxy bid.
+    pub proxy_max_bid: u64,
+    // Rest of the tick size of this listing next to tick_size_constant_in_lamports, see
+    // TickSize. If all of them are 0 the auction house's default_tick_size is used.
+    pub tick_size_percent: u8,
+    pub tick_size_min_in_lamports: u64,
+    pub tick_size_max_in_lamports: u64,
+    // Whether the primary sale proceeds go to the mint's RevenueSplit, see set_revenue_split.
+    pub has_revenue_split: bool,
+    // The auction house's buyer_premium_basis_points when the current bid was placed. The
+    // premium on the current bid is escrowed with it, see get_escrowed_bid_amount.
+    pub buyer_premium_basis_points: u16,
+    // Whether admin overrides of this account are recorded in the mint's LastBidPriceAuditLog,
+    // see create_last_bid_price_audit_log.
+    pub has_audit_log: bool,
 }
 
+pub const LAST_BID_PRICE_VERSION: u8 = 1;
+
 pub const LAST_BID_PRICE_SIZE: usize = 8 + // Discriminator
 8 + // price
 33 + // bidder
 1 + // has_been_sold
 8 + // tick_size_constant_in_lamports
 1 + // has_campaign_escrow_treasury
-85; // padding
+33 + // rent_payer
+1 + // version
+1 + // bidder_uses_shared_escrow
+9 + // buy_now_price
+8 + // proxy_max_bid
+1 + // tick_size_percent
+8 + // tick_size_min_in_lamports
+8 + // tick_size_max_in_lamports
+1 + // has_revenue_split
+2 + // buyer_premium_basis_points
+1 + // has_audit_log
+LAST_BID_PRICE_PADDING;
+
+pub const LAST_BID_PRICE_PADDING: usize = 12;
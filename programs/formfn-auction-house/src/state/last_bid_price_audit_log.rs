@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LastBidPriceOverrideKind {
+    SetLastBidPrice,
+    SetPreviousBidder,
+    SetHasBeenSold,
+}
+
+// The LastBidPrice fields which admin overrides can change. bidder is ZERO_PUBKEY if there is
+// none, so that every entry of the log has the same size.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct LastBidPriceSnapshot {
+    pub price: u64,
+    pub bidder: Pubkey,
+    pub has_been_sold: u8,
+}
+
+pub const LAST_BID_PRICE_SNAPSHOT_SIZE: usize = 8 + // price
+32 + // bidder
+1; // has_been_sold
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LastBidPriceOverride {
+    pub kind: LastBidPriceOverrideKind,
+    pub signer: Pubkey,
+    pub unix_timestamp: i64,
+    pub old: LastBidPriceSnapshot,
+    pub new: LastBidPriceSnapshot,
+}
+
+pub const LAST_BID_PRICE_OVERRIDE_SIZE: usize = 1 + // kind
+32 + // signer
+8 + // unix_timestamp
+LAST_BID_PRICE_SNAPSHOT_SIZE + // old
+LAST_BID_PRICE_SNAPSHOT_SIZE; // new
+
+// Append-only record of the admin overrides of a mint's LastBidPrice, i.e. set_last_bid_price,
+// set_previous_bidder and set_has_been_sold. Once created LastBidPrice.has_audit_log is set and
+// every override must append to it. The account grows with each entry, and must hold enough
+// lamports to stay rent exempt, which anyone can top up.
+#[account]
+#[derive(Default)]
+pub struct LastBidPriceAuditLog {
+    pub auction_house: Pubkey,
+    pub mint: Pubkey,
+    pub bump: u8,
+    // Layout version of this account, see LAST_BID_PRICE_AUDIT_LOG_VERSION.
+    pub version: u8,
+    pub entries: Vec<LastBidPriceOverride>,
+}
+
+pub const LAST_BID_PRICE_AUDIT_LOG_VERSION: u8 = 1;
+
+// Offset of the length prefix of entries, which record_last_bid_price_override updates
+// directly so that the log does not have to be deserialized.
+pub const LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET: usize = 8 + // Discriminator
+32 + // auction_house
+32 + // mint
+1 + // bump
+1; // version
+
+// Size of the log without entries, see get_last_bid_price_audit_log_size.
+pub const LAST_BID_PRICE_AUDIT_LOG_SIZE: usize = LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET +
+4 + // extra overhead for vector storage
+LAST_BID_PRICE_AUDIT_LOG_PADDING;
+
+pub const LAST_BID_PRICE_AUDIT_LOG_PADDING: usize = 64;
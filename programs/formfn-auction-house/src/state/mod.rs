@@ -1,15 +1,33 @@
 pub mod auction_house;
+pub mod auction_house_roles;
+pub mod claimable_balance;
 pub mod edition_allowlist_settings;
 pub mod edition_buyer_info;
 pub mod edition_distributor;
+pub mod fee_override;
 pub mod last_bid_price;
+pub mod last_bid_price_audit_log;
 pub mod price_function;
+pub mod revenue_split;
+pub mod sealed_bid_auction;
+pub mod shared_escrow;
+pub mod tick_size;
 pub mod trade_state;
+pub mod treasury_split;
 
 pub use auction_house::*;
+pub use auction_house_roles::*;
+pub use claimable_balance::*;
 pub use edition_allowlist_settings::*;
 pub use edition_buyer_info::*;
 pub use edition_distributor::*;
+pub use fee_override::*;
 pub use last_bid_price::*;
+pub use last_bid_price_audit_log::*;
 pub use price_function::*;
+pub use revenue_split::*;
+pub use sealed_bid_auction::*;
+pub use shared_escrow::*;
+pub use tick_size::*;
 pub use trade_state::*;
+pub use treasury_split::*;
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REVENUE_SPLIT_RECIPIENTS;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct RevenueSplitRecipient {
+    pub address: Pubkey,
+    pub basis_points: u16,
+}
+
+pub const REVENUE_SPLIT_RECIPIENT_SIZE: usize = 32 + // address
+2; // basis_points
+
+// Splits the primary sale proceeds of a mint between arbitrary recipients, instead of paying
+// the seller or splitting them between the metadata creators. Only used by execute_sale_v2 if
+// LastBidPrice.has_revenue_split is set, and by buy_edition_v2 if
+// EditionDistributor.has_revenue_split is set.
+#[account]
+#[derive(Default)]
+pub struct RevenueSplit {
+    pub auction_house: Pubkey,
+    pub mint: Pubkey,
+    // Basis points add up to 10,000.
+    pub recipients: Vec<RevenueSplitRecipient>,
+    pub bump: u8,
+    // Layout version of this account, see REVENUE_SPLIT_VERSION.
+    pub version: u8,
+}
+
+pub const REVENUE_SPLIT_VERSION: u8 = 1;
+
+pub const REVENUE_SPLIT_SIZE: usize = 8 + // Discriminator
+32 + // auction_house
+32 + // mint
+4 + // extra overhead for vector storage
+MAX_REVENUE_SPLIT_RECIPIENTS * REVENUE_SPLIT_RECIPIENT_SIZE + // recipients
+1 + // bump
+1 + // version
+REVENUE_SPLIT_PADDING;
+
+pub const REVENUE_SPLIT_PADDING: usize = 64;
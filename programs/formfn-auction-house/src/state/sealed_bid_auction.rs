@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+// Configuration and results of a sealed bid auction for an NFT. Bidders commit to a bid
+// before commit_end_time (see SealedBid), reveal it before reveal_end_time, and the sale
+// is then settled with execute_sale_v2 to the highest revealed bidder.
+#[account]
+#[derive(Default)]
+pub struct SealedBidAuction {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub seller: Pubkey,
+    // Revealed bids below this price can't win.
+    pub reserve_price: u64,
+    // Unix time (seconds since epoch)
+    pub commit_end_time: i64,
+    // Unix time (seconds since epoch)
+    pub reveal_end_time: i64,
+    // If true, the winner pays the second highest revealed bid (or the reserve price if it
+    // is higher) instead of their own bid.
+    pub is_second_price: bool,
+    pub highest_bid: u64,
+    pub highest_bidder: Option<Pubkey>,
+    pub second_highest_bid: u64,
+    pub number_of_bids: u32,
+    pub number_of_reveals: u32,
+    pub number_of_refunds: u32,
+    pub is_settled: bool,
+    pub bump: u8,
+    // Layout version of this account, see SEALED_BID_AUCTION_VERSION.
+    pub version: u8,
+}
+
+pub const SEALED_BID_AUCTION_VERSION: u8 = 1;
+
+pub const SEALED_BID_AUCTION_SIZE: usize = 8 + // Discriminator
+32 + // auction_house
+32 + // token_mint
+32 + // seller
+8 + // reserve_price
+8 + // commit_end_time
+8 + // reveal_end_time
+1 + // is_second_price
+8 + // highest_bid
+33 + // highest_bidder
+8 + // second_highest_bid
+4 + // number_of_bids
+4 + // number_of_reveals
+4 + // number_of_refunds
+1 + // is_settled
+1 + // bump
+1 + // version
+SEALED_BID_AUCTION_PADDING;
+
+pub const SEALED_BID_AUCTION_PADDING: usize = 64;
+
+// A bidder's commitment in a sealed bid auction. The deposit is held in the bid's own
+// escrow, see SEALED_BID_ESCROW, so it can't be withdrawn before the auction is settled,
+// and must be at least the revealed price.
+#[account]
+#[derive(Default)]
+pub struct SealedBid {
+    pub sealed_bid_auction: Pubkey,
+    pub bidder: Pubkey,
+    // See get_sealed_bid_commitment.
+    pub commitment: [u8; 32],
+    pub deposit: u64,
+    pub revealed_price: Option<u64>,
+    pub bump: u8,
+    // Layout version of this account, see SEALED_BID_VERSION.
+    pub version: u8,
+    pub escrow_bump: u8,
+}
+
+pub const SEALED_BID_VERSION: u8 = 1;
+
+pub const SEALED_BID_SIZE: usize = 8 + // Discriminator
+32 + // sealed_bid_auction
+32 + // bidder
+32 + // commitment
+8 + // deposit
+9 + // revealed_price
+1 + // bump
+1 + // version
+1 + // escrow_bump
+SEALED_BID_PADDING;
+
+pub const SEALED_BID_PADDING: usize = 31;
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+// Per wallet escrow (for native auction houses) which can fund bids on any number of
+// auctions. The lamports above the rent exempt minimum are the wallet's balance, and
+// reserved_amount is the part of it backing the wallet's current highest bids. Which
+// auctions those are is tracked by LastBidPrice.bidder_uses_shared_escrow.
+#[account]
+#[derive(Default)]
+pub struct SharedEscrow {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub reserved_amount: u64,
+    // Layout version of this account, see SHARED_ESCROW_VERSION.
+    pub version: u8,
+}
+
+pub const SHARED_ESCROW_VERSION: u8 = 1;
+
+pub const SHARED_ESCROW_SIZE: usize = 8 + // Discriminator
+32 + // wallet
+32 + // auction_house
+1 + // bump
+8 + // reserved_amount
+1 + // version
+SHARED_ESCROW_PADDING;
+
+pub const SHARED_ESCROW_PADDING: usize = 64;
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+// Minimum difference between consecutive auction bids. If constant_in_lamports is set it is
+// used as is, otherwise percent of the current price is used if set, and otherwise the
+// default of 10% rounded down to 0.1 units. The result is then clamped to min_in_lamports and
+// max_in_lamports, each of which is ignored if 0.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct TickSize {
+    pub constant_in_lamports: u64,
+    pub percent: u8,
+    pub min_in_lamports: u64,
+    pub max_in_lamports: u64,
+}
+
+pub const TICK_SIZE_SIZE: usize = 8 + // constant_in_lamports
+1 + // percent
+8 + // min_in_lamports
+8; // max_in_lamports
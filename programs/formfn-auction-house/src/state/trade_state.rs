@@ -1,13 +1,15 @@
+use anchor_lang::prelude::*;
 use num_derive::FromPrimitive;
 
 // Will be used to check sale_type set in trade state accounts
 // NOTE: Keep in sync with JS enum at src/types/enums/SaleType.ts
-#[derive(Eq, FromPrimitive, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, FromPrimitive, PartialEq)]
 #[repr(u8)]
 pub enum TradeStateSaleType {
     Auction = 1,
     InstantSale = 2,
     Offer = 3,
+    SealedBidAuction = 4,
 }
 
 // Needed for msg!
@@ -17,11 +19,53 @@ impl std::fmt::Display for TradeStateSaleType {
             TradeStateSaleType::Auction => write!(f, "Auction"),
             TradeStateSaleType::InstantSale => write!(f, "InstantSale"),
             TradeStateSaleType::Offer => write!(f, "Offer"),
+            TradeStateSaleType::SealedBidAuction => write!(f, "SealedBidAuction"),
         }
     }
 }
 
-pub const TRADE_STATE_SIZE_U16: u16 = 1 + // bump
-1 + // sale type Enum
-128; // padding
-pub const TRADE_STATE_SIZE: usize = TRADE_STATE_SIZE_U16 as usize;
+/**
+ * Created by sell and buy_v2 (or up front with create_trade_state) for every listing, bid and
+ * offer. The seeds are the same as for legacy trade states, which are raw accounts where byte 0
+ * is the bump and byte 1, if present, is the sale type.
+ *
+ * Legacy trade states are only read by migrate_legacy_trade_state, and every other instruction
+ * rejects them. The authority migrates the ones which are still open after the upgrade, and once
+ * none are left migrate_legacy_trade_state can be removed.
+ */
+#[account]
+#[derive(Default)]
+pub struct TradeState {
+    // Zeroed once the sale is executed.
+    pub bump: u8,
+    // Layout version of this account, see TRADE_STATE_VERSION.
+    pub version: u8,
+    // A TradeStateSaleType, stored as its u8 value like the sale_type instruction args.
+    pub sale_type: u8,
+    pub seller: Pubkey,
+    // Pubkey::default() for listings, which don't have a buyer yet.
+    pub buyer: Pubkey,
+    pub token_mint: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub created_at: i64,
+    // Unix time (seconds since epoch) after which the trade state can no longer be executed.
+    pub expiry: Option<i64>,
+}
+
+pub const TRADE_STATE_VERSION: u8 = 1;
+
+pub const TRADE_STATE_SIZE: usize = 8 + // Discriminator
+1 + // bump
+1 + // version
+1 + // sale_type
+32 + // seller
+32 + // buyer
+32 + // token_mint
+8 + // price
+8 + // token_size
+8 + // created_at
+9 + // expiry
+TRADE_STATE_PADDING;
+
+pub const TRADE_STATE_PADDING: usize = 32;
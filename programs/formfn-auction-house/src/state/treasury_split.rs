@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_REVENUE_SPLIT_RECIPIENTS, RevenueSplitRecipient, REVENUE_SPLIT_RECIPIENT_SIZE,
+};
+
+// Splits an auction house's treasury between its recipients, see set_treasury_split and
+// distribute_treasury. Changes to the recipients are pending until
+// TREASURY_SPLIT_DELAY_IN_SECONDS has passed, see apply_pending_treasury_split. While there
+// are recipients, withdraw_from_treasury and sweeping the treasury are disabled.
+#[account]
+#[derive(Default)]
+pub struct TreasurySplit {
+    pub auction_house: Pubkey,
+    // Basis points add up to 10,000.
+    pub recipients: Vec<RevenueSplitRecipient>,
+    pub bump: u8,
+    // Layout version of this account, see TREASURY_SPLIT_VERSION.
+    pub version: u8,
+    // Empty if the recipients are being removed. None if no change is pending.
+    pub pending_recipients: Option<Vec<RevenueSplitRecipient>>,
+    // Unix time (seconds since epoch) after which pending_recipients can be applied.
+    pub pending_recipients_effective_time: i64,
+}
+
+pub const TREASURY_SPLIT_VERSION: u8 = 1;
+
+pub const TREASURY_SPLIT_SIZE: usize = 8 + // Discriminator
+32 + // auction_house
+4 + // extra overhead for vector storage
+MAX_REVENUE_SPLIT_RECIPIENTS * REVENUE_SPLIT_RECIPIENT_SIZE + // recipients
+1 + // bump
+1 + // version
+1 + 4 + MAX_REVENUE_SPLIT_RECIPIENTS * REVENUE_SPLIT_RECIPIENT_SIZE + // pending_recipients
+8 + // pending_recipients_effective_time
+TREASURY_SPLIT_PADDING;
+
+pub const TREASURY_SPLIT_PADDING: usize = 64;
@@ -0,0 +1,293 @@
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+
+    use crate::{
+        AuctionHouse, AuctionHouseRoles, ClaimableBalance, EditionAllowlistSettings,
+        EditionBuyerInfoAccount, EditionDistributor, FeeOverride, LastBidPrice,
+        LastBidPriceAuditLog, LastBidPriceOverride, LastBidPriceOverrideKind, LastBidPriceSnapshot,
+        PriceFunction, PriceFunctionType, RevenueSplit, RevenueSplitRecipient, SealedBid,
+        SealedBidAuction, SharedEscrow, TickSize, TradeState, TreasurySplit, AUCTION_HOUSE_PADDING,
+        AUCTION_HOUSE_ROLES_PADDING, AUCTION_HOUSE_ROLES_SIZE, AUCTION_HOUSE_SIZE,
+        CLAIMABLE_BALANCE_PADDING, CLAIMABLE_BALANCE_SIZE, EDITION_ALLOWLIST_SETTINGS_PADDING,
+        EDITION_ALLOWLIST_SETTINGS_SIZE, EDITION_BUYER_INFO_ACCOUNT_PADDING,
+        EDITION_BUYER_INFO_ACCOUNT_SPACE, EDITION_DISTRIBUTOR_PADDING, EDITION_DISTRIBUTOR_SIZE,
+        FEE_OVERRIDE_PADDING, FEE_OVERRIDE_SIZE, LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET,
+        LAST_BID_PRICE_AUDIT_LOG_PADDING, LAST_BID_PRICE_AUDIT_LOG_SIZE,
+        LAST_BID_PRICE_OVERRIDE_SIZE, LAST_BID_PRICE_PADDING, LAST_BID_PRICE_SIZE,
+        LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE, MAX_NUMBER_OF_PRICE_PARAMS,
+        MAX_REVENUE_SPLIT_RECIPIENTS, NUMBER_OF_MERKLE_ROOTS_TO_STORE, REVENUE_SPLIT_PADDING,
+        REVENUE_SPLIT_SIZE, SEALED_BID_AUCTION_PADDING, SEALED_BID_AUCTION_SIZE, SEALED_BID_PADDING,
+        SEALED_BID_SIZE, SHARED_ESCROW_PADDING, SHARED_ESCROW_SIZE, TRADE_STATE_PADDING,
+        TRADE_STATE_SIZE, TREASURY_SPLIT_PADDING, TREASURY_SPLIT_SIZE,
+    };
+
+    const DISCRIMINATOR_SIZE: usize = 8;
+
+    /**
+     * Checks that the hand-counted size matches the Borsh layout of the largest possible
+     * instance of the account (i.e. all Options are Some and all Vecs are full).
+     */
+    fn check_account_size<T: AnchorSerialize>(account: &T, padding: usize, size: usize) {
+        let serialized_size = account.try_to_vec().unwrap().len();
+        assert_eq!(DISCRIMINATOR_SIZE + serialized_size + padding, size);
+    }
+
+    #[test]
+    fn auction_house_size_test() {
+        let auction_house = AuctionHouse {
+            auction_house_fee_account: Pubkey::default(),
+            auction_house_treasury: Pubkey::default(),
+            treasury_withdrawal_destination: Pubkey::default(),
+            fee_withdrawal_destination: Pubkey::default(),
+            treasury_mint: Pubkey::default(),
+            authority: Pubkey::default(),
+            creator: Pubkey::default(),
+            bump: 0,
+            treasury_bump: 0,
+            fee_payer_bump: 0,
+            seller_fee_basis_points: 0,
+            requires_sign_off: false,
+            can_change_sale_price: false,
+            seller_fee_basis_points_secondary: 0,
+            pay_all_fees: false,
+            version: 0,
+            default_tick_size: TickSize::default(),
+            claimable_balances_enabled: false,
+            buyer_premium_basis_points: 0,
+            pending_authority: Some(Pubkey::default()),
+            pending_treasury_withdrawal_destination: Some(Pubkey::default()),
+            pending_fee_withdrawal_destination: Some(Pubkey::default()),
+            pending_seller_fee_basis_points: Some(0),
+            pending_seller_fee_basis_points_secondary: Some(0),
+            pending_fees_effective_time: 0,
+            paused: false,
+            guardian: Pubkey::default(),
+            has_treasury_split: false,
+        };
+
+        check_account_size(&auction_house, AUCTION_HOUSE_PADDING, AUCTION_HOUSE_SIZE);
+    }
+
+    #[test]
+    fn last_bid_price_size_test() {
+        let last_bid_price = LastBidPrice {
+            price: 0,
+            bidder: Some(Pubkey::default()),
+            has_been_sold: 0,
+            tick_size_constant_in_lamports: 0,
+            has_campaign_escrow_treasury: false,
+            rent_payer: Some(Pubkey::default()),
+            version: 0,
+            bidder_uses_shared_escrow: false,
+            buy_now_price: Some(0),
+            proxy_max_bid: 0,
+            tick_size_percent: 0,
+            tick_size_min_in_lamports: 0,
+            tick_size_max_in_lamports: 0,
+            has_revenue_split: false,
+            buyer_premium_basis_points: 0,
+            has_audit_log: false,
+        };
+
+        check_account_size(&last_bid_price, LAST_BID_PRICE_PADDING, LAST_BID_PRICE_SIZE);
+    }
+
+    #[test]
+    fn edition_distributor_size_test() {
+        let edition_distributor = EditionDistributor {
+            bump: 0,
+            master_edition_mint: Pubkey::default(),
+            owner: Pubkey::default(),
+            price_function: PriceFunction {
+                starting_price_lamports: 0,
+                price_function_type: PriceFunctionType::Constant,
+                params: vec![0.0; MAX_NUMBER_OF_PRICE_PARAMS],
+            },
+            public_sale_start_time: 0,
+            sale_end_time: Some(0),
+            anti_bot_protection_enabled: false,
+            limit_per_address: 0,
+            treasury_mint: Pubkey::default(),
+            allowlist_sale_start_time: Some(0),
+            allowlist_sale_price: Some(0),
+            allowlist_number_sold: 0,
+            max_editions_to_sell: Some(0),
+            is_supply_locked: false,
+            reserved_number_minted: 0,
+            bot_tax_amount: Some(0),
+            bot_tax_recipient: Some(Pubkey::default()),
+            total_number_sold: 0,
+            total_gross_revenue: 0,
+            total_platform_fees: 0,
+            total_creator_proceeds: 0,
+            version: 0,
+            has_revenue_split: false,
+        };
+
+        check_account_size(
+            &edition_distributor,
+            EDITION_DISTRIBUTOR_PADDING,
+            EDITION_DISTRIBUTOR_SIZE,
+        );
+    }
+
+    #[test]
+    fn edition_allowlist_settings_size_test() {
+        let edition_allowlist_settings = EditionAllowlistSettings {
+            bump: 0,
+            version: 0,
+            merkle_roots: vec![[0; 32]; NUMBER_OF_MERKLE_ROOTS_TO_STORE],
+        };
+
+        check_account_size(
+            &edition_allowlist_settings,
+            EDITION_ALLOWLIST_SETTINGS_PADDING,
+            EDITION_ALLOWLIST_SETTINGS_SIZE,
+        );
+    }
+
+    #[test]
+    fn edition_buyer_info_account_size_test() {
+        check_account_size(
+            &EditionBuyerInfoAccount::default(),
+            EDITION_BUYER_INFO_ACCOUNT_PADDING,
+            EDITION_BUYER_INFO_ACCOUNT_SPACE,
+        );
+    }
+
+    #[test]
+    fn shared_escrow_size_test() {
+        check_account_size(&SharedEscrow::default(), SHARED_ESCROW_PADDING, SHARED_ESCROW_SIZE);
+    }
+
+    #[test]
+    fn claimable_balance_size_test() {
+        check_account_size(
+            &ClaimableBalance::default(),
+            CLAIMABLE_BALANCE_PADDING,
+            CLAIMABLE_BALANCE_SIZE,
+        );
+    }
+
+    #[test]
+    fn revenue_split_size_test() {
+        let revenue_split = RevenueSplit {
+            recipients: vec![RevenueSplitRecipient::default(); MAX_REVENUE_SPLIT_RECIPIENTS],
+            ..Default::default()
+        };
+
+        check_account_size(&revenue_split, REVENUE_SPLIT_PADDING, REVENUE_SPLIT_SIZE);
+    }
+
+    #[test]
+    fn fee_override_size_test() {
+        let fee_override = FeeOverride {
+            expiry: Some(0),
+            ..Default::default()
+        };
+
+        check_account_size(&fee_override, FEE_OVERRIDE_PADDING, FEE_OVERRIDE_SIZE);
+    }
+
+    #[test]
+    fn treasury_split_size_test() {
+        let treasury_split = TreasurySplit {
+            recipients: vec![RevenueSplitRecipient::default(); MAX_REVENUE_SPLIT_RECIPIENTS],
+            pending_recipients: Some(vec![
+                RevenueSplitRecipient::default();
+                MAX_REVENUE_SPLIT_RECIPIENTS
+            ]),
+            ..Default::default()
+        };
+
+        check_account_size(&treasury_split, TREASURY_SPLIT_PADDING, TREASURY_SPLIT_SIZE);
+    }
+
+    #[test]
+    fn auction_house_roles_size_test() {
+        check_account_size(
+            &AuctionHouseRoles::default(),
+            AUCTION_HOUSE_ROLES_PADDING,
+            AUCTION_HOUSE_ROLES_SIZE,
+        );
+    }
+
+    #[test]
+    fn last_bid_price_audit_log_size_test() {
+        // The log grows by LAST_BID_PRICE_OVERRIDE_SIZE per entry, so the base size is empty.
+        check_account_size(
+            &LastBidPriceAuditLog::default(),
+            LAST_BID_PRICE_AUDIT_LOG_PADDING,
+            LAST_BID_PRICE_AUDIT_LOG_SIZE,
+        );
+
+        let entry = LastBidPriceOverride {
+            kind: LastBidPriceOverrideKind::SetLastBidPrice,
+            signer: Pubkey::default(),
+            unix_timestamp: 0,
+            old: LastBidPriceSnapshot::default(),
+            new: LastBidPriceSnapshot::default(),
+        };
+        assert_eq!(entry.try_to_vec().unwrap().len(), LAST_BID_PRICE_OVERRIDE_SIZE);
+
+        // record_last_bid_price_override writes the length prefix of entries directly.
+        let audit_log = LastBidPriceAuditLog {
+            entries: vec![entry; 3],
+            ..Default::default()
+        };
+        let serialized = audit_log.try_to_vec().unwrap();
+        let entries_offset = LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET - DISCRIMINATOR_SIZE;
+        assert_eq!(serialized[entries_offset..entries_offset + 4], 3u32.to_le_bytes());
+    }
+
+    #[test]
+    fn sealed_bid_auction_size_test() {
+        let sealed_bid_auction = SealedBidAuction {
+            highest_bidder: Some(Pubkey::default()),
+            ..Default::default()
+        };
+
+        check_account_size(
+            &sealed_bid_auction,
+            SEALED_BID_AUCTION_PADDING,
+            SEALED_BID_AUCTION_SIZE,
+        );
+    }
+
+    #[test]
+    fn sealed_bid_size_test() {
+        let sealed_bid = SealedBid {
+            revealed_price: Some(0),
+            ..Default::default()
+        };
+
+        check_account_size(&sealed_bid, SEALED_BID_PADDING, SEALED_BID_SIZE);
+    }
+
+    #[test]
+    fn trade_state_size_test() {
+        let trade_state = TradeState {
+            expiry: Some(0),
+            ..Default::default()
+        };
+
+        check_account_size(&trade_state, TRADE_STATE_PADDING, TRADE_STATE_SIZE);
+    }
+
+    /**
+     * New fields must consume the padding rather than grow the accounts, since existing
+     * accounts were allocated with these sizes. The exception is EditionAllowlistSettings,
+     * whose version goes before its merkle_roots, so legacy accounts are grown and shifted
+     * by migrate_account. Legacy trade states are raw accounts, which are rewritten as
+     * TradeState accounts by migrate_legacy_trade_state.
+     */
+    #[test]
+    fn account_sizes_unchanged_test() {
+        assert_eq!(AUCTION_HOUSE_SIZE, 461);
+        assert_eq!(LAST_BID_PRICE_SIZE, 144);
+        assert_eq!(EDITION_DISTRIBUTOR_SIZE, 403);
+        assert_eq!(LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE, 3341);
+        assert_eq!(EDITION_BUYER_INFO_ACCOUNT_SPACE, 74);
+    }
+}
@@ -1 +1,2 @@
+pub mod account_size_test;
 pub mod get_price_for_edition_test;
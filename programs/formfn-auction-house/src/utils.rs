@@ -1,10 +1,21 @@
 use anchor_lang::solana_program;
 
 use {
-    crate::constants::{FEE_PAYER, PREFIX},
+    crate::constants::{
+        AUCTION_HOUSE_ROLES, CLAIMABLE_BALANCE, FEE_OVERRIDE, FEE_PAYER, LAST_BID_PRICE_AUDIT_LOG,
+        MAX_REVENUE_SPLIT_RECIPIENTS, PREFIX, REVENUE_SPLIT, SEALED_BID, SEALED_BID_AUCTION,
+        SEALED_BID_ESCROW, SHARED_ESCROW,
+    },
     crate::{
-        AuctionHouse, AuctionHouseError, LastBidPrice, PriceFunction, PriceFunctionType,
-        TradeStateSaleType,
+        AuctionHouse, AuctionHouseError, AuctionHouseRole, AuctionHouseRoles, ClaimableBalance,
+        EditionAllowlistSettings, FeeOverride, LastBidPrice, LastBidPriceAuditLog,
+        LastBidPriceOverride, LastBidPriceOverrideKind, LastBidPriceOverriddenEvent,
+        LastBidPriceSnapshot, PriceFunction, PriceFunctionType, RevenueSplit, RevenueSplitRecipient,
+        SealedBid, SealedBidAuction, SharedEscrow, TickSize, TradeState, TradeStateSaleType,
+        CLAIMABLE_BALANCE_SIZE, CLAIMABLE_BALANCE_VERSION, EDITION_ALLOWLIST_SETTINGS_SIZE,
+        EDITION_ALLOWLIST_SETTINGS_VERSION, EDITION_ALLOWLIST_SETTINGS_VERSION_OFFSET,
+        LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET, LAST_BID_PRICE_AUDIT_LOG_SIZE,
+        LAST_BID_PRICE_OVERRIDE_SIZE, LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE,
     },
     anchor_lang::{
         prelude::*,
@@ -212,6 +223,75 @@ pub fn assert_valid_auction_house(program_id: &Pubkey, auction_house: &Pubkey) -
     Ok(())
 }
 
+/**
+ * Sales and purchases are disabled while the auction house is paused, see set_paused.
+ */
+pub fn assert_not_paused(auction_house: &AuctionHouse) -> Result<()> {
+    if auction_house.paused {
+        return Err(AuctionHouseError::AuctionHousePaused.into());
+    }
+
+    Ok(())
+}
+
+/**
+ * Whether the key holds the role. Unassigned roles are held by nobody.
+ */
+pub fn has_auction_house_role(
+    auction_house_roles: &AuctionHouseRoles,
+    role: AuctionHouseRole,
+    key: &Pubkey,
+) -> bool {
+    let role_key = match role {
+        AuctionHouseRole::FeeManager => auction_house_roles.fee_manager,
+        AuctionHouseRole::TreasuryManager => auction_house_roles.treasury_manager,
+        AuctionHouseRole::ListingModerator => auction_house_roles.listing_moderator,
+        AuctionHouseRole::DropManager => auction_house_roles.drop_manager,
+    };
+    role_key != ZERO_PUBKEY && role_key == *key
+}
+
+/**
+ * Checks that the signer is the auction house authority or has the role, in which case the
+ * auction house's AuctionHouseRoles must be passed in remaining_accounts.
+ */
+pub fn assert_authority_or_role<'info>(
+    auction_house: &anchor_lang::prelude::Account<'info, AuctionHouse>,
+    signer: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    role: AuctionHouseRole,
+) -> Result<()> {
+    if !signer.is_signer {
+        return Err(AuctionHouseError::NoValidSignerPresent.into());
+    }
+
+    if signer.key() == auction_house.authority {
+        return Ok(());
+    }
+
+    let (auction_house_roles_key, _) = Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            AUCTION_HOUSE_ROLES.as_bytes(),
+        ],
+        program_id,
+    );
+    let auction_house_roles_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == auction_house_roles_key)
+        .ok_or(AuctionHouseError::MissingAuctionHouseRole)?;
+    let auction_house_roles =
+        anchor_lang::prelude::Account::<AuctionHouseRoles>::try_from(auction_house_roles_info)?;
+
+    if !has_auction_house_role(&auction_house_roles, role, &signer.key()) {
+        return Err(AuctionHouseError::MissingAuctionHouseRole.into());
+    }
+
+    Ok(())
+}
+
 fn assert_last_bid_price_derivation(
     program_id: &Pubkey,
     last_bid_price: &AccountInfo,
@@ -268,6 +348,123 @@ pub fn assert_valid_anti_bot_authority(
     Ok(())
 }
 
+/**
+ * A fee override no longer applies once its expiry has passed.
+ */
+pub fn is_fee_override_active(fee_override: &FeeOverride, unix_timestamp: i64) -> bool {
+    match fee_override.expiry {
+        Some(expiry) => unix_timestamp < expiry,
+        None => true,
+    }
+}
+
+/**
+ * Returns the fee override of the mint, or else the fee override of the first verified
+ * creator of the metadata which has one. Only fee overrides passed in remaining_accounts are
+ * considered, and expired overrides are skipped. Returns None if there is no active
+ * override, in which case the auction house's fees apply.
+ *
+ * Since overrides are capped at the auction house's fees (see get_seller_fee_basis_points),
+ * leaving one out can only make the sale pay the full fees.
+ */
+pub fn get_fee_override_from_remaining_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    auction_house: &Pubkey,
+    mint: &Pubkey,
+    metadata: &Metadata,
+    unix_timestamp: i64,
+) -> Result<Option<FeeOverride>> {
+    let verified_creators = metadata
+        .data
+        .creators
+        .iter()
+        .flatten()
+        .filter(|creator| creator.verified)
+        .map(|creator| creator.address);
+
+    for key in std::iter::once(*mint).chain(verified_creators) {
+        let (fee_override_key, _) = Pubkey::find_program_address(
+            &[
+                PREFIX.as_bytes(),
+                auction_house.as_ref(),
+                key.as_ref(),
+                FEE_OVERRIDE.as_bytes(),
+            ],
+            program_id,
+        );
+        let fee_override_info = match remaining_accounts
+            .iter()
+            .find(|account| account.key() == fee_override_key)
+        {
+            Some(fee_override_info) if !fee_override_info.data_is_empty() => fee_override_info,
+            _ => continue,
+        };
+
+        let fee_override =
+            anchor_lang::prelude::Account::<FeeOverride>::try_from(fee_override_info)?;
+        if is_fee_override_active(&fee_override, unix_timestamp) {
+            return Ok(Some(fee_override.into_inner()));
+        }
+    }
+
+    Ok(None)
+}
+
+/**
+ * The platform fee for a primary or secondary sale, taken from the fee override if there is
+ * one. Overrides can only lower fees, since raising them must go through the fee increase
+ * delay of update_auction_house, so they are capped at the auction house's current fees.
+ */
+pub fn get_seller_fee_basis_points(
+    auction_house: &AuctionHouse,
+    fee_override: Option<&FeeOverride>,
+    has_been_sold: bool,
+) -> u16 {
+    let auction_house_fee = if has_been_sold {
+        auction_house.seller_fee_basis_points_secondary
+    } else {
+        auction_house.seller_fee_basis_points
+    };
+
+    match (fee_override, has_been_sold) {
+        (Some(fee_override), true) => {
+            std::cmp::min(fee_override.seller_fee_basis_points_secondary, auction_house_fee)
+        }
+        (Some(fee_override), false) => {
+            std::cmp::min(fee_override.seller_fee_basis_points, auction_house_fee)
+        }
+        (None, _) => auction_house_fee,
+    }
+}
+
+/**
+ * Changes to the authority and withdrawal destinations only take effect once accepted, so
+ * update_auction_house proposes them instead. Passing the current key cancels a proposal.
+ */
+pub fn get_pending_key(current: &Pubkey, new: &Pubkey) -> Option<Pubkey> {
+    if new == current {
+        None
+    } else {
+        Some(*new)
+    }
+}
+
+/**
+ * Fee increases proposed with update_auction_house can only be applied once their effective
+ * time has passed.
+ */
+pub fn assert_pending_fees_effective(
+    pending_fees_effective_time: i64,
+    unix_timestamp: i64,
+) -> Result<()> {
+    if unix_timestamp < pending_fees_effective_time {
+        return Err(AuctionHouseError::PendingFeesNotEffective.into());
+    }
+
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn pay_auction_house_fees<'a>(
     auction_house: &anchor_lang::prelude::Account<'a, AuctionHouse>,
@@ -279,12 +476,9 @@ pub fn pay_auction_house_fees<'a>(
     size: u64,
     is_native: bool,
     has_been_sold: bool,
+    fee_override: Option<&FeeOverride>,
 ) -> Result<u64> {
-    let fees = if has_been_sold {
-        auction_house.seller_fee_basis_points_secondary
-    } else {
-        auction_house.seller_fee_basis_points
-    };
+    let fees = get_seller_fee_basis_points(auction_house, fee_override, has_been_sold);
     let total_fee = (fees as u128)
         .checked_mul(size as u128)
         .ok_or(AuctionHouseError::NumericalOverflow)?
@@ -326,6 +520,61 @@ pub fn pay_auction_house_fees<'a>(
     Ok(total_fee)
 }
 
+/**
+ * Pays the buyer premium on an auction's winning bid from the buyer's escrow to the treasury.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn pay_buyer_premium<'a>(
+    auction_house: &anchor_lang::prelude::Account<'a, AuctionHouse>,
+    auction_house_treasury: &AccountInfo<'a>,
+    escrow_payment_account: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+    buyer_premium: u64,
+    is_native: bool,
+) -> Result<()> {
+    if buyer_premium == 0 {
+        return Ok(());
+    }
+
+    if !is_native {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                &escrow_payment_account.key,
+                &auction_house_treasury.key,
+                &auction_house.key(),
+                &[],
+                buyer_premium,
+            )?,
+            &[
+                escrow_payment_account.clone(),
+                auction_house_treasury.clone(),
+                token_program.clone(),
+                auction_house.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+    } else {
+        invoke_signed(
+            &system_instruction::transfer(
+                &escrow_payment_account.key,
+                auction_house_treasury.key,
+                buyer_premium,
+            ),
+            &[
+                escrow_payment_account.clone(),
+                auction_house_treasury.clone(),
+                system_program.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
 pub fn create_program_token_account_if_not_present<'a>(
     payment_account: &UncheckedAccount<'a>,
     system_program: &Program<'a, System>,
@@ -437,6 +686,79 @@ pub fn pay_creator_fees<'a>(
     is_native: bool,
 ) -> Result<u64> {
     let metadata = Metadata::from_account_info(metadata_info)?;
+    let (creator_fees, buyer_leftover) = get_creator_fee_amounts(&metadata, size)?;
+    if metadata.data.creators.is_none() {
+        msg!("No creators found in metadata");
+    }
+    for (creator_address, creator_fee) in creator_fees {
+        let current_creator_info = next_account_info(remaining_accounts)?;
+        assert_keys_equal(creator_address, *current_creator_info.key)?;
+        if !is_native {
+            let current_creator_token_account_info = next_account_info(remaining_accounts)?;
+            if current_creator_token_account_info.data_is_empty() {
+                make_ata(
+                    current_creator_token_account_info.to_account_info(),
+                    current_creator_info.to_account_info(),
+                    treasury_mint.to_account_info(),
+                    fee_payer.to_account_info(),
+                    ata_program.to_account_info(),
+                    token_program.to_account_info(),
+                    system_program.to_account_info(),
+                    rent.to_account_info(),
+                    fee_payer_seeds,
+                )?;
+            }
+            assert_is_ata(
+                current_creator_token_account_info,
+                current_creator_info.key,
+                &treasury_mint.key(),
+            )?;
+            if creator_fee > 0 {
+                invoke_signed(
+                    &spl_token::instruction::transfer(
+                        token_program.key,
+                        &escrow_payment_account.key,
+                        current_creator_token_account_info.key,
+                        payment_account_owner.key,
+                        &[],
+                        creator_fee,
+                    )?,
+                    &[
+                        escrow_payment_account.clone(),
+                        current_creator_token_account_info.clone(),
+                        token_program.clone(),
+                        payment_account_owner.clone(),
+                    ],
+                    &[signer_seeds],
+                )?;
+            }
+        } else if creator_fee > 0 {
+            invoke_signed(
+                &system_instruction::transfer(
+                    &escrow_payment_account.key,
+                    current_creator_info.key,
+                    creator_fee,
+                ),
+                &[
+                    escrow_payment_account.clone(),
+                    current_creator_info.clone(),
+                    system_program.clone(),
+                ],
+                &[signer_seeds],
+            )?;
+        }
+    }
+    Ok(buyer_leftover)
+}
+
+/**
+ * Returns the royalty owed to each creator in the metadata for a sale of the given size, and
+ * what is left for the seller.
+ */
+pub fn get_creator_fee_amounts(
+    metadata: &Metadata,
+    size: u64,
+) -> Result<(Vec<(Pubkey, u64)>, u64)> {
     let fees = metadata.data.seller_fee_basis_points;
     let total_fee = (fees as u128)
         .checked_mul(size as u128)
@@ -447,84 +769,28 @@ pub fn pay_creator_fees<'a>(
     let remaining_size = size
         .checked_sub(total_fee)
         .ok_or(AuctionHouseError::NumericalOverflow)?;
-    match metadata.data.creators {
-        Some(creators) => {
-            for creator in creators {
-                let pct = creator.share as u128;
-                let creator_fee =
-                    pct.checked_mul(total_fee as u128)
-                        .ok_or(AuctionHouseError::NumericalOverflow)?
-                        .checked_div(100)
-                        .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
-                remaining_fee = remaining_fee
-                    .checked_sub(creator_fee)
-                    .ok_or(AuctionHouseError::NumericalOverflow)?;
-                let current_creator_info = next_account_info(remaining_accounts)?;
-                assert_keys_equal(creator.address, *current_creator_info.key)?;
-                if !is_native {
-                    let current_creator_token_account_info = next_account_info(remaining_accounts)?;
-                    if current_creator_token_account_info.data_is_empty() {
-                        make_ata(
-                            current_creator_token_account_info.to_account_info(),
-                            current_creator_info.to_account_info(),
-                            treasury_mint.to_account_info(),
-                            fee_payer.to_account_info(),
-                            ata_program.to_account_info(),
-                            token_program.to_account_info(),
-                            system_program.to_account_info(),
-                            rent.to_account_info(),
-                            fee_payer_seeds,
-                        )?;
-                    }
-                    assert_is_ata(
-                        current_creator_token_account_info,
-                        current_creator_info.key,
-                        &treasury_mint.key(),
-                    )?;
-                    if creator_fee > 0 {
-                        invoke_signed(
-                            &spl_token::instruction::transfer(
-                                token_program.key,
-                                &escrow_payment_account.key,
-                                current_creator_token_account_info.key,
-                                payment_account_owner.key,
-                                &[],
-                                creator_fee,
-                            )?,
-                            &[
-                                escrow_payment_account.clone(),
-                                current_creator_token_account_info.clone(),
-                                token_program.clone(),
-                                payment_account_owner.clone(),
-                            ],
-                            &[signer_seeds],
-                        )?;
-                    }
-                } else if creator_fee > 0 {
-                    invoke_signed(
-                        &system_instruction::transfer(
-                            &escrow_payment_account.key,
-                            current_creator_info.key,
-                            creator_fee,
-                        ),
-                        &[
-                            escrow_payment_account.clone(),
-                            current_creator_info.clone(),
-                            system_program.clone(),
-                        ],
-                        &[signer_seeds],
-                    )?;
-                }
-            }
-        }
-        None => {
-            msg!("No creators found in metadata");
+    let mut creator_fees = vec![];
+    if let Some(creators) = &metadata.data.creators {
+        for creator in creators {
+            let pct = creator.share as u128;
+            let creator_fee = pct
+                .checked_mul(total_fee as u128)
+                .ok_or(AuctionHouseError::NumericalOverflow)?
+                .checked_div(100)
+                .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
+            remaining_fee = remaining_fee
+                .checked_sub(creator_fee)
+                .ok_or(AuctionHouseError::NumericalOverflow)?;
+            creator_fees.push((creator.address, creator_fee));
         }
     }
     // Any dust is returned to the party posting the NFT
-    Ok(remaining_size
-        .checked_add(remaining_fee)
-        .ok_or(AuctionHouseError::NumericalOverflow)?)
+    Ok((
+        creator_fees,
+        remaining_size
+            .checked_add(remaining_fee)
+            .ok_or(AuctionHouseError::NumericalOverflow)?,
+    ))
 }
 
 /// Cheap method to just grab mint Pubkey from token account, instead of deserializing entire thing
@@ -690,6 +956,78 @@ pub fn get_min_price_diff_in_lamports(
         .ok_or(AuctionHouseError::NumericalOverflow.into());
 }
 
+pub fn get_last_bid_price_tick_size(last_bid_price: &LastBidPrice) -> TickSize {
+    TickSize {
+        constant_in_lamports: last_bid_price.tick_size_constant_in_lamports,
+        percent: last_bid_price.tick_size_percent,
+        min_in_lamports: last_bid_price.tick_size_min_in_lamports,
+        max_in_lamports: last_bid_price.tick_size_max_in_lamports,
+    }
+}
+
+/**
+ * A listing's own tick size takes precedence over the auction house's default tick size.
+ */
+pub fn get_effective_tick_size(
+    last_bid_price: &LastBidPrice,
+    default_tick_size: &TickSize,
+) -> TickSize {
+    let tick_size = get_last_bid_price_tick_size(last_bid_price);
+    if tick_size == TickSize::default() {
+        *default_tick_size
+    } else {
+        tick_size
+    }
+}
+
+pub fn assert_valid_tick_size(tick_size: &TickSize, decimals: u8) -> Result<()> {
+    // A constant of 0 is allowed, because 0 means that the percent or default tick size
+    // should be used.
+    if tick_size.constant_in_lamports != 0
+        && decimals > 0
+        && tick_size.constant_in_lamports < get_min_price_difference_for_decimals(decimals)
+    {
+        return Err(AuctionHouseError::TickSizeTooLow.into());
+    }
+
+    if tick_size.percent > 100 {
+        return Err(AuctionHouseError::InvalidTickSizePercent.into());
+    }
+
+    if tick_size.constant_in_lamports != 0 && tick_size.percent != 0 {
+        return Err(AuctionHouseError::ConflictingTickSizes.into());
+    }
+
+    if tick_size.max_in_lamports != 0 && tick_size.min_in_lamports > tick_size.max_in_lamports {
+        return Err(AuctionHouseError::InvalidTickSizeBounds.into());
+    }
+
+    Ok(())
+}
+
+/**
+ * Returns the minimum difference between the given price and the next bid, see TickSize.
+ */
+pub fn get_tick_size_in_lamports(price: u64, tick_size: &TickSize, decimals: u8) -> Result<u64> {
+    let tick_size_in_lamports = if tick_size.percent != 0 && tick_size.constant_in_lamports == 0 {
+        let percent_of_price = (price as u128)
+            .checked_mul(tick_size.percent as u128)
+            .and_then(|value| value.checked_div(100))
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+        // Bids must always go up by at least 1 lamport.
+        std::cmp::max(percent_of_price as u64, 1)
+    } else {
+        get_min_price_diff_in_lamports(price, tick_size.constant_in_lamports, decimals)?
+    };
+
+    let tick_size_in_lamports = std::cmp::max(tick_size_in_lamports, tick_size.min_in_lamports);
+    if tick_size.max_in_lamports != 0 {
+        return Ok(std::cmp::min(tick_size_in_lamports, tick_size.max_in_lamports));
+    }
+
+    Ok(tick_size_in_lamports)
+}
+
 /**
  * Helper fn for generic withdrawals.
  */
@@ -821,6 +1159,40 @@ pub fn withdraw_helper<'info>(
     Ok(())
 }
 
+/**
+ * A LastBidPrice account can only be closed if there is no live auction for the NFT. It must
+ * also not be the only record of the NFT's primary sale (see get_has_been_sold), nor hold
+ * an audit log, revenue split or tick size, since a recreated account would start without
+ * them.
+ */
+pub fn assert_last_bid_price_closable(
+    last_bid_price: &LastBidPrice,
+    primary_sale_happened: bool,
+) -> Result<()> {
+    let has_bidder = match last_bid_price.bidder {
+        Some(bidder) => bidder != ZERO_PUBKEY,
+        None => false,
+    };
+
+    if last_bid_price.price != 0 || has_bidder {
+        return Err(AuctionHouseError::LastBidPriceAuctionInProgress.into());
+    }
+
+    if last_bid_price.has_been_sold == 1 && !primary_sale_happened {
+        return Err(AuctionHouseError::LastBidPriceRecordsPrimarySale.into());
+    }
+
+    let has_tick_size = last_bid_price.tick_size_constant_in_lamports != 0
+        || last_bid_price.tick_size_percent != 0
+        || last_bid_price.tick_size_min_in_lamports != 0
+        || last_bid_price.tick_size_max_in_lamports != 0;
+    if last_bid_price.has_audit_log || last_bid_price.has_revenue_split || has_tick_size {
+        return Err(AuctionHouseError::LastBidPriceHasSettings.into());
+    }
+
+    Ok(())
+}
+
 /**
  * Returns has_been_sold by checking the metadata.primary_sale_happened and
  * falling back to last_bid_price.has_been_sold if metadata.primary_sale_happened
@@ -829,7 +1201,8 @@ pub fn withdraw_helper<'info>(
  * We do this since metadata.primary_sale_happened can only be set by the update_authority
  * and thus in scenarios where the update_authority of the metadata is not the one executing
  * the sale (e.g., instant sales), the metadata will not be updated to reflect the primary sale
- * having happened.
+ * having happened. execute_sale_v2 does update it when the seller signs, see
+ * update_primary_sale_happened_via_token.
  */
 pub fn get_has_been_sold(metadata: &Metadata, last_bid_price: Option<&LastBidPrice>) -> bool {
     return match last_bid_price {
@@ -838,6 +1211,40 @@ pub fn get_has_been_sold(metadata: &Metadata, last_bid_price: Option<&LastBidPri
     };
 }
 
+/**
+ * Marks the primary sale as having happened in the token metadata. Token Metadata only lets
+ * the update authority or the token owner do this, so it is only possible if the seller signs
+ * (being the program_as_signer delegate is not enough) and the metadata is passed as writable.
+ * The token must still be in the seller's token account. Returns whether it was updated.
+ */
+pub fn update_primary_sale_happened_via_token<'a>(
+    metadata_info: &AccountInfo<'a>,
+    seller: &AccountInfo<'a>,
+    token_account: &AccountInfo<'a>,
+    token_metadata_program: &AccountInfo<'a>,
+) -> Result<bool> {
+    if !seller.is_signer || !metadata_info.is_writable {
+        return Ok(false);
+    }
+
+    invoke(
+        &mpl_token_metadata::instruction::update_primary_sale_happened_via_token(
+            mpl_token_metadata::id(),
+            *metadata_info.key,
+            *seller.key,
+            *token_account.key,
+        ),
+        &[
+            metadata_info.clone(),
+            seller.clone(),
+            token_account.clone(),
+            token_metadata_program.clone(),
+        ],
+    )?;
+
+    Ok(true)
+}
+
 /**
  * Returns true if the primary sale should be split between the creators in the metadata creators array.
  *
@@ -864,6 +1271,76 @@ pub fn should_split_primary_sale(
     }
 }
 
+/**
+ * The signer's key along with the keys of any signers passed in remaining_accounts.
+ */
+pub fn get_signer_keys(signer: &Signer, remaining_accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    std::iter::once(signer.key())
+        .chain(
+            remaining_accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.key()),
+        )
+        .collect()
+}
+
+/**
+ * A revenue split replaces the seller's proceeds and, when there are several creators, each
+ * creator's primary sale share (see should_split_primary_sale). So for multi-creator mints
+ * every verified creator must be among the signers.
+ */
+pub fn assert_revenue_split_signed_by_creators(
+    metadata: &Metadata,
+    signers: &[Pubkey],
+) -> Result<()> {
+    let creators = match &metadata.data.creators {
+        Some(creators) if creators.len() > 1 => creators,
+        _ => return Ok(()),
+    };
+
+    let has_missing_signer = creators
+        .iter()
+        .filter(|creator| creator.verified)
+        .any(|creator| !signers.contains(&creator.address));
+    if has_missing_signer {
+        return Err(AuctionHouseError::MissingCreatorSignature.into());
+    }
+
+    Ok(())
+}
+
+/**
+ * Returns the part of a primary sale owed to each creator in the metadata.
+ */
+pub fn get_primary_sale_amounts(
+    metadata: &Metadata,
+    total_amount: u64,
+) -> Result<Vec<(Pubkey, u64)>> {
+    let mut remaining_amount = total_amount;
+    let mut creator_amounts = vec![];
+    if let Some(creators) = &metadata.data.creators {
+        for (index, creator) in creators.iter().enumerate() {
+            let pct = creator.share as u128;
+            let creator_amount = if index != creators.len() - 1 {
+                pct.checked_mul(total_amount as u128)
+                    .ok_or(AuctionHouseError::NumericalOverflow)?
+                    .checked_div(100)
+                    .ok_or(AuctionHouseError::NumericalOverflow)? as u64
+            } else {
+                // Leftover amount due to imprecision given to last creator
+                remaining_amount
+            };
+            remaining_amount = remaining_amount
+                .checked_sub(creator_amount)
+                .ok_or(AuctionHouseError::NumericalOverflow)?;
+            creator_amounts.push((creator.address, creator_amount));
+        }
+    }
+
+    Ok(creator_amounts)
+}
+
 /**
  * Basically the same as pay_creator_fees, but splits the primary sale between creators.
  *
@@ -881,43 +1358,25 @@ pub fn split_primary_sale_between_creators_native<'a>(
     total_amount: u64,
 ) -> Result<()> {
     let metadata = Metadata::from_account_info(metadata_info)?;
-    let mut remaining_amount = total_amount;
-    match metadata.data.creators {
-        Some(creators) => {
-            for (index, creator) in creators.iter().enumerate() {
-                let pct = creator.share as u128;
-                let creator_amount = if index != creators.len() - 1 {
-                    pct.checked_mul(total_amount as u128)
-                        .ok_or(AuctionHouseError::NumericalOverflow)?
-                        .checked_div(100)
-                        .ok_or(AuctionHouseError::NumericalOverflow)? as u64
-                } else {
-                    // Leftover amount due to imprecision given to last creator
-                    remaining_amount
-                };
-                remaining_amount = remaining_amount
-                    .checked_sub(creator_amount)
-                    .ok_or(AuctionHouseError::NumericalOverflow)?;
-                let current_creator_info = next_account_info(remaining_accounts)?;
-                assert_keys_equal(creator.address, *current_creator_info.key)?;
-                invoke_signed(
-                    &system_instruction::transfer(
-                        &payer_account.key,
-                        current_creator_info.key,
-                        creator_amount,
-                    ),
-                    &[
-                        payer_account.clone(),
-                        current_creator_info.clone(),
-                        system_program.clone(),
-                    ],
-                    &[signer_seeds],
-                )?;
-            }
-        }
-        None => {
-            msg!("No creators found in metadata");
-        }
+    if metadata.data.creators.is_none() {
+        msg!("No creators found in metadata");
+    }
+    for (creator_address, creator_amount) in get_primary_sale_amounts(&metadata, total_amount)? {
+        let current_creator_info = next_account_info(remaining_accounts)?;
+        assert_keys_equal(creator_address, *current_creator_info.key)?;
+        invoke_signed(
+            &system_instruction::transfer(
+                &payer_account.key,
+                current_creator_info.key,
+                creator_amount,
+            ),
+            &[
+                payer_account.clone(),
+                current_creator_info.clone(),
+                system_program.clone(),
+            ],
+            &[signer_seeds],
+        )?;
     }
 
     Ok(())
@@ -947,67 +1406,49 @@ pub fn split_primary_sale_between_creators_non_native<'a>(
     total_amount: u64,
 ) -> Result<()> {
     let metadata = Metadata::from_account_info(metadata_info)?;
-    let mut remaining_amount = total_amount;
-    match metadata.data.creators {
-        Some(creators) => {
-            for (index, creator) in creators.iter().enumerate() {
-                let pct = creator.share as u128;
-                let creator_amount = if index != creators.len() - 1 {
-                    pct.checked_mul(total_amount as u128)
-                        .ok_or(AuctionHouseError::NumericalOverflow)?
-                        .checked_div(100)
-                        .ok_or(AuctionHouseError::NumericalOverflow)? as u64
-                } else {
-                    // Leftover amount due to imprecision given to last creator
-                    remaining_amount
-                };
-                remaining_amount = remaining_amount
-                    .checked_sub(creator_amount)
-                    .ok_or(AuctionHouseError::NumericalOverflow)?;
-                let current_creator_info = next_account_info(remaining_accounts)?;
-                assert_keys_equal(creator.address, *current_creator_info.key)?;
-                let current_creator_token_account_info = next_account_info(remaining_accounts)?;
-                if current_creator_token_account_info.data_is_empty() {
-                    make_ata(
-                        current_creator_token_account_info.to_account_info(),
-                        current_creator_info.to_account_info(),
-                        treasury_mint.to_account_info(),
-                        fee_payer.to_account_info(),
-                        ata_program.to_account_info(),
-                        token_program.to_account_info(),
-                        system_program.to_account_info(),
-                        rent.to_account_info(),
-                        fee_payer_seeds,
-                    )?;
-                }
-                assert_is_ata(
-                    current_creator_token_account_info,
-                    current_creator_info.key,
-                    &treasury_mint.key(),
-                )?;
-                if creator_amount > 0 {
-                    invoke_signed(
-                        &spl_token::instruction::transfer(
-                            token_program.key,
-                            &payer_account.key,
-                            current_creator_token_account_info.key,
-                            payment_account_owner.key,
-                            &[],
-                            creator_amount,
-                        )?,
-                        &[
-                            payer_account.clone(),
-                            current_creator_token_account_info.clone(),
-                            token_program.clone(),
-                            payment_account_owner.clone(),
-                        ],
-                        &[signer_seeds],
-                    )?;
-                }
-            }
+    if metadata.data.creators.is_none() {
+        msg!("No creators found in metadata");
+    }
+    for (creator_address, creator_amount) in get_primary_sale_amounts(&metadata, total_amount)? {
+        let current_creator_info = next_account_info(remaining_accounts)?;
+        assert_keys_equal(creator_address, *current_creator_info.key)?;
+        let current_creator_token_account_info = next_account_info(remaining_accounts)?;
+        if current_creator_token_account_info.data_is_empty() {
+            make_ata(
+                current_creator_token_account_info.to_account_info(),
+                current_creator_info.to_account_info(),
+                treasury_mint.to_account_info(),
+                fee_payer.to_account_info(),
+                ata_program.to_account_info(),
+                token_program.to_account_info(),
+                system_program.to_account_info(),
+                rent.to_account_info(),
+                fee_payer_seeds,
+            )?;
         }
-        None => {
-            msg!("No creators found in metadata");
+        assert_is_ata(
+            current_creator_token_account_info,
+            current_creator_info.key,
+            &treasury_mint.key(),
+        )?;
+        if creator_amount > 0 {
+            invoke_signed(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    &payer_account.key,
+                    current_creator_token_account_info.key,
+                    payment_account_owner.key,
+                    &[],
+                    creator_amount,
+                )?,
+                &[
+                    payer_account.clone(),
+                    current_creator_token_account_info.clone(),
+                    token_program.clone(),
+                    payment_account_owner.clone(),
+                ],
+                &[signer_seeds],
+            )?;
         }
     }
 
@@ -1077,78 +1518,1023 @@ pub fn get_price_for_edition(
     }
 }
 
-pub fn get_trade_state_sale_type(trade_state: &AccountInfo) -> TradeStateSaleType {
-    if trade_state.data_len() > 1 {
-        let sale_type = trade_state.data.borrow()[1];
-        return FromPrimitive::from_u8(sale_type).unwrap_or(TradeStateSaleType::Auction);
+pub fn assert_valid_trade_state_sale_type(sale_type: u8) -> Result<TradeStateSaleType> {
+    FromPrimitive::from_u8(sale_type).ok_or(AuctionHouseError::InvalidTradeStateSaleType.into())
+}
+
+/**
+ * Legacy trade states have to be migrated with migrate_legacy_trade_state before they can be
+ * read, see TradeState.
+ */
+pub fn get_trade_state(trade_state: &AccountInfo) -> Result<TradeState> {
+    if trade_state.data_is_empty() {
+        return Err(anchor_lang::error::ErrorCode::AccountNotInitialized.into());
+    }
+
+    let data = trade_state.try_borrow_data()?;
+    if !is_trade_state(&data) {
+        return Err(AuctionHouseError::TradeStateNeedsMigration.into());
+    }
+
+    TradeState::try_deserialize(&mut &data[..])
+}
+
+pub fn write_trade_state(trade_state: &AccountInfo, trade_state_data: &TradeState) -> Result<()> {
+    let mut data = trade_state.try_borrow_mut_data()?;
+    let mut data_slice: &mut [u8] = &mut data;
+    trade_state_data.try_serialize(&mut data_slice)
+}
+
+pub fn is_trade_state(data: &[u8]) -> bool {
+    data.len() >= 8 && data[..8] == <TradeState as anchor_lang::Discriminator>::discriminator()
+}
+
+/**
+ * sell and buy_v2 create trade states which don't exist yet as auctions, otherwise the trade
+ * state must not have expired.
+ */
+pub fn get_sell_or_buy_sale_type(
+    trade_state: &AccountInfo,
+    unix_timestamp: i64,
+) -> Result<TradeStateSaleType> {
+    if trade_state.data_is_empty() {
+        return Ok(TradeStateSaleType::Auction);
+    }
+
+    let trade_state_data = get_trade_state(trade_state)?;
+    assert_trade_state_not_expired(&trade_state_data, unix_timestamp)?;
+    assert_valid_trade_state_sale_type(trade_state_data.sale_type)
+}
+
+/**
+ * Returns the seller and buyer of a trade state. The wallet owns the token account for
+ * listings, which don't have a buyer yet, and is the buyer for bids and offers.
+ */
+pub fn get_trade_state_parties(wallet: Pubkey, token_account_owner: Pubkey) -> (Pubkey, Pubkey) {
+    if wallet == token_account_owner {
+        (wallet, Pubkey::default())
     } else {
-        // Default to this
-        return TradeStateSaleType::Auction;
+        (token_account_owner, wallet)
     }
 }
 
 /**
- * For now, we allow end_time = None
+ * Returns the bump and sale type of a legacy trade state, see TradeState. 1 byte trade states
+ * and ones with an unknown sale type were handled as auctions. Only used by
+ * migrate_legacy_trade_state.
+ */
+pub fn parse_legacy_trade_state(data: &[u8]) -> Result<(u8, TradeStateSaleType)> {
+    // Executed trade states have their bump zeroed.
+    if data.is_empty() || data[0] == 0 || is_trade_state(data) {
+        return Err(AuctionHouseError::TradeStateNotLegacy.into());
+    }
+
+    let sale_type = data
+        .get(1)
+        .and_then(|sale_type| FromPrimitive::from_u8(*sale_type))
+        .unwrap_or(TradeStateSaleType::Auction);
+    Ok((data[0], sale_type))
+}
+
+pub fn assert_trade_state_not_expired(trade_state: &TradeState, unix_timestamp: i64) -> Result<()> {
+    if let Some(expiry) = trade_state.expiry {
+        if unix_timestamp >= expiry {
+            return Err(AuctionHouseError::TradeStateExpired.into());
+        }
+    }
+
+    Ok(())
+}
+
+/**
+ * The buy now price of an auction no longer applies once the highest bid reaches it.
+ */
+pub fn get_active_buy_now_price(buy_now_price: Option<u64>, highest_bid: u64) -> Option<u64> {
+    buy_now_price.filter(|buy_now_price| highest_bid < *buy_now_price)
+}
+
+/**
+ * The buyer premium charged on top of a winning bid of the given amount. Can't overflow since
+ * basis_points is at most 10000.
+ */
+pub fn get_buyer_premium(amount: u64, basis_points: u16) -> u64 {
+    ((amount as u128) * (basis_points as u128) / 10000) as u64
+}
+
+/**
+ * The most the current bidder has agreed to pay, which is the max bid for proxy bids.
+ */
+pub fn get_max_bid(last_bid_price: &LastBidPrice) -> u64 {
+    if last_bid_price.proxy_max_bid > 0 {
+        last_bid_price.proxy_max_bid
+    } else {
+        last_bid_price.price
+    }
+}
+
+/**
+ * The price the token can be bought at with buy_now, if any. The buy now price doesn't apply
+ * once the highest bid reaches it, which includes the max bid of a proxy bid since that bidder
+ * already agreed to pay it.
+ */
+pub fn get_buy_now_purchase_price(last_bid_price: &LastBidPrice) -> Option<u64> {
+    get_active_buy_now_price(last_bid_price.buy_now_price, get_max_bid(last_bid_price))
+}
+
+/**
+ * The amount escrowed for the current bid, which is the max bid plus the buyer premium on it.
+ */
+pub fn get_escrowed_bid_amount(last_bid_price: &LastBidPrice) -> u64 {
+    let max_bid = get_max_bid(last_bid_price);
+    max_bid.saturating_add(get_buyer_premium(
+        max_bid,
+        last_bid_price.buyer_premium_basis_points,
+    ))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AuctionBidOutcome {
+    // False if the standing proxy bid countered the incoming bid and is still the highest bid.
+    pub is_highest_bid: bool,
+    // The visible price of the auction after the bid.
+    pub price: u64,
+}
+
+fn get_min_next_bid(price: u64, tick_size: &TickSize, decimals: u8) -> Result<u64> {
+    let min_price_diff = get_tick_size_in_lamports(price, tick_size, decimals)?;
+    price
+        .checked_add(min_price_diff)
+        .ok_or(AuctionHouseError::NumericalOverflow.into())
+}
+
+/**
+ * Resolves an incoming auction bid against the current bid. max_bid is the amount escrowed
+ * by the bidder and bid is the visible price they ask for, which are equal for regular bids.
  *
- * We may want to revisit this in the future. But product-wise, it seems
- * like we may want to have an open edition that never ends.
+ * A standing proxy bid automatically counters incoming bids up to its max bid, one tick above
+ * the incoming max bid. A winning proxy bid only raises the visible price as far as needed to
+ * beat the previous bid. Expects last_bid_price.buy_now_price to be refreshed already, and
+ * tick_size to be the effective tick size of the listing.
  */
-pub fn assert_valid_edition_sale_times(
-    allowlist_sale_start_time: Option<i64>,
-    public_sale_start_time: i64,
-    sale_end_time: Option<i64>,
-    unix_timestamp: i64,
+pub fn resolve_auction_bid(
+    last_bid_price: &LastBidPrice,
+    bidder: &Pubkey,
+    bid: u64,
+    max_bid: u64,
+    tick_size: &TickSize,
+    decimals: u8,
+) -> Result<AuctionBidOutcome> {
+    if bid > max_bid {
+        return Err(AuctionHouseError::InvalidMaxBid.into());
+    }
+
+    // Bids at or above the buy now price don't need to respect the tick size.
+    let buy_now_price = last_bid_price
+        .buy_now_price
+        .filter(|buy_now_price| max_bid >= *buy_now_price);
+
+    if last_bid_price.price == 0 {
+        let price = match buy_now_price {
+            Some(buy_now_price) => std::cmp::max(bid, buy_now_price),
+            None => bid,
+        };
+        return Ok(AuctionBidOutcome {
+            is_highest_bid: true,
+            price,
+        });
+    }
+
+    let min_price = get_min_next_bid(last_bid_price.price, tick_size, decimals)?;
+    let floor = match buy_now_price {
+        Some(buy_now_price) => std::cmp::max(bid, buy_now_price),
+        None if max_bid < min_price => return Err(AuctionHouseError::BidTooLow.into()),
+        None => std::cmp::max(bid, min_price),
+    };
+
+    // The current bidder raising their own bid or max bid doesn't bid against themselves.
+    if last_bid_price.bidder == Some(*bidder) {
+        return Ok(AuctionBidOutcome {
+            is_highest_bid: true,
+            price: std::cmp::max(last_bid_price.price, bid),
+        });
+    }
+
+    let proxy_max_bid = last_bid_price.proxy_max_bid;
+    if proxy_max_bid >= max_bid {
+        let counter_price = std::cmp::min(
+            proxy_max_bid,
+            get_min_next_bid(max_bid, tick_size, decimals)?,
+        );
+        return Ok(AuctionBidOutcome {
+            is_highest_bid: false,
+            price: std::cmp::max(last_bid_price.price, counter_price),
+        });
+    }
+
+    let price = if proxy_max_bid > 0 {
+        std::cmp::max(
+            floor,
+            get_min_next_bid(proxy_max_bid, tick_size, decimals)?,
+        )
+    } else {
+        floor
+    };
+
+    Ok(AuctionBidOutcome {
+        is_highest_bid: true,
+        price: std::cmp::min(price, max_bid),
+    })
+}
+
+/**
+ * Returns the part of a SharedEscrow's balance which is not reserved for bids, i.e. which
+ * can be used for new bids or withdrawn.
+ */
+pub fn get_shared_escrow_available_balance(
+    lamports: u64,
+    rent_exempt_minimum: u64,
+    reserved_amount: u64,
+) -> u64 {
+    lamports
+        .saturating_sub(rent_exempt_minimum)
+        .saturating_sub(reserved_amount)
+}
+
+pub fn reserve_shared_escrow_amount(
+    shared_escrow: &mut anchor_lang::prelude::Account<SharedEscrow>,
+    amount: u64,
 ) -> Result<()> {
-    // Allowlist sale is enabled
-    if let Some(allowlist_sale_start_time_val) = allowlist_sale_start_time {
-        // Note: a 0 public_sale_start_time represents an allowlist sale with
-        // no public sale.
-        if public_sale_start_time != 0 && allowlist_sale_start_time_val >= public_sale_start_time {
-            return Err(AuctionHouseError::AllowlistSaleMustBeBeforePublicSale.into());
-        }
+    let shared_escrow_info = shared_escrow.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(shared_escrow_info.data_len());
+    let available_balance = get_shared_escrow_available_balance(
+        shared_escrow_info.lamports(),
+        rent_exempt_minimum,
+        shared_escrow.reserved_amount,
+    );
+    if available_balance < amount {
+        return Err(AuctionHouseError::InsufficientSharedEscrowBalance.into());
     }
 
-    // Because public_sale_start_time of 0 signifies an allowlist sale with NO
-    // public sale, it can only be 0 if an allowlist_sale_start_time is provided.
-    if public_sale_start_time == 0 && allowlist_sale_start_time.is_none() {
-        return Err(AuctionHouseError::PublicSaleStartTimeCannotBeZero.into());
+    shared_escrow.reserved_amount = shared_escrow
+        .reserved_amount
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+pub fn release_shared_escrow_amount(shared_escrow: &mut SharedEscrow, amount: u64) -> Result<()> {
+    shared_escrow.reserved_amount = shared_escrow
+        .reserved_amount
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(())
+}
+
+/**
+ * Finds the SharedEscrow of the given wallet in remaining_accounts. Callers must call exit
+ * on the returned account to persist any changes.
+ */
+pub fn get_shared_escrow_from_remaining_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    auction_house: &Pubkey,
+    wallet: &Pubkey,
+) -> Result<anchor_lang::prelude::Account<'info, SharedEscrow>> {
+    let (shared_escrow_key, _) = Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            auction_house.as_ref(),
+            wallet.as_ref(),
+            SHARED_ESCROW.as_bytes(),
+        ],
+        program_id,
+    );
+    let shared_escrow_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == shared_escrow_key)
+        .ok_or(AuctionHouseError::SharedEscrowNotProvided)?;
+
+    anchor_lang::prelude::Account::<SharedEscrow>::try_from(shared_escrow_info)
+}
+
+/**
+ * Moves amount from an escrow payment account to the recipient's ClaimableBalance, which
+ * must be in remaining_accounts and is created at the fee payer's expense if it doesn't
+ * exist yet. For SPL auction houses the tokens are held in the ClaimableBalance's
+ * associated token account, which must also be in remaining_accounts. Nothing is created
+ * for zero amounts.
+ *
+ * signer_seeds are the escrow's seeds for native auction houses, and the auction house's
+ * seeds otherwise, since it owns token escrows.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn credit_claimable_balance<'a>(
+    remaining_accounts: &[AccountInfo<'a>],
+    program_id: &Pubkey,
+    auction_house: &anchor_lang::prelude::Account<'a, AuctionHouse>,
+    recipient: &Pubkey,
+    escrow_payment_account: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    treasury_mint: &AccountInfo<'a>,
+    ata_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+    fee_payer_seeds: &[&[u8]],
+    amount: u64,
+    is_native: bool,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
     }
 
-    if let Some(sale_end_time_val) = sale_end_time {
-        // Validate sale_end_time by itself
-        if unix_timestamp >= sale_end_time_val {
-            return Err(AuctionHouseError::EndTimeMustBeInFuture.into());
-        }
+    let auction_house_key = auction_house.key();
+    let (claimable_balance_key, bump) = Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            recipient.as_ref(),
+            CLAIMABLE_BALANCE.as_bytes(),
+        ],
+        program_id,
+    );
+    let claimable_balance_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == claimable_balance_key)
+        .ok_or(AuctionHouseError::ClaimableBalanceNotProvided)?;
 
-        // Validate both start_time and sale_end_time
-        if public_sale_start_time >= sale_end_time_val {
-            return Err(AuctionHouseError::EndTimeMustComeAfterStartTime.into());
-        }
+    if claimable_balance_info.data_is_empty() {
+        create_or_allocate_account_raw(
+            *program_id,
+            claimable_balance_info,
+            rent,
+            system_program,
+            fee_payer,
+            CLAIMABLE_BALANCE_SIZE,
+            fee_payer_seeds,
+            &[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                recipient.as_ref(),
+                CLAIMABLE_BALANCE.as_bytes(),
+                &[bump],
+            ],
+        )?;
+        let claimable_balance = ClaimableBalance {
+            auction_house: auction_house_key,
+            recipient: *recipient,
+            amount: 0,
+            bump,
+            version: CLAIMABLE_BALANCE_VERSION,
+        };
+        let mut data = claimable_balance_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        claimable_balance.try_serialize(&mut writer)?;
+    }
 
-        // Validate allowlist sale time is not after sale_end_time
-        if let Some(allowlist_sale_start_time_val) = allowlist_sale_start_time {
-            if allowlist_sale_start_time_val >= sale_end_time_val {
-                return Err(AuctionHouseError::AllowlistSaleCannotBeAfterEndTime.into());
-            }
+    if is_native {
+        invoke_signed(
+            &system_instruction::transfer(
+                escrow_payment_account.key,
+                claimable_balance_info.key,
+                amount,
+            ),
+            &[
+                escrow_payment_account.clone(),
+                claimable_balance_info.clone(),
+                system_program.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+    } else {
+        let claimable_balance_token_account_key =
+            get_associated_token_address(&claimable_balance_key, treasury_mint.key);
+        let claimable_balance_token_account = remaining_accounts
+            .iter()
+            .find(|account| account.key() == claimable_balance_token_account_key)
+            .ok_or(AuctionHouseError::ClaimableBalanceNotProvided)?;
+        if claimable_balance_token_account.data_is_empty() {
+            make_ata(
+                claimable_balance_token_account.clone(),
+                claimable_balance_info.clone(),
+                treasury_mint.clone(),
+                fee_payer.clone(),
+                ata_program.clone(),
+                token_program.clone(),
+                system_program.clone(),
+                rent.clone(),
+                fee_payer_seeds,
+            )?;
         }
+
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                escrow_payment_account.key,
+                claimable_balance_token_account.key,
+                &auction_house_key,
+                &[],
+                amount,
+            )?,
+            &[
+                escrow_payment_account.clone(),
+                claimable_balance_token_account.clone(),
+                token_program.clone(),
+                auction_house.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
     }
 
-    return Ok(());
+    let mut claimable_balance =
+        anchor_lang::prelude::Account::<ClaimableBalance>::try_from(claimable_balance_info)?;
+    claimable_balance.amount = claimable_balance
+        .amount
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    claimable_balance.exit(program_id)
 }
 
-pub fn assert_valid_times_for_buy_edition(
-    allowlist_sale_start_time: Option<i64>,
-    public_sale_start_time: i64,
-    sale_end_time: Option<i64>,
-    unix_timestamp: i64,
-) -> Result<()> {
-    if let Some(allowlist_sale_start_time) = allowlist_sale_start_time {
-        if unix_timestamp < allowlist_sale_start_time {
-            return Err(AuctionHouseError::BuyEditionTooEarly.into());
+/**
+ * A revenue split either has no recipients, which disables it, or recipients whose basis
+ * points add up to 10,000.
+ */
+pub fn assert_valid_revenue_split_recipients(recipients: &[RevenueSplitRecipient]) -> Result<()> {
+    if recipients.is_empty() {
+        return Ok(());
+    }
+
+    if recipients.len() > MAX_REVENUE_SPLIT_RECIPIENTS {
+        return Err(AuctionHouseError::TooManyRevenueSplitRecipients.into());
+    }
+
+    let mut total_basis_points: u16 = 0;
+    for recipient in recipients {
+        if recipient.basis_points == 0 {
+            return Err(AuctionHouseError::InvalidRevenueSplitBasisPoints.into());
         }
+        total_basis_points = total_basis_points
+            .checked_add(recipient.basis_points)
+            .ok_or(AuctionHouseError::InvalidRevenueSplitBasisPoints)?;
+    }
+    if total_basis_points != 10000 {
+        return Err(AuctionHouseError::InvalidRevenueSplitBasisPoints.into());
+    }
 
-        if is_during_allowlist_sale(
-            Some(allowlist_sale_start_time),
+    Ok(())
+}
+
+/**
+ * Returns the part of total_amount owed to each recipient of the revenue split.
+ */
+pub fn get_revenue_split_amounts(
+    recipients: &[RevenueSplitRecipient],
+    total_amount: u64,
+) -> Result<Vec<(Pubkey, u64)>> {
+    let mut remaining_amount = total_amount;
+    let mut recipient_amounts = vec![];
+    for (index, recipient) in recipients.iter().enumerate() {
+        let recipient_amount = if index != recipients.len() - 1 {
+            (recipient.basis_points as u128)
+                .checked_mul(total_amount as u128)
+                .ok_or(AuctionHouseError::NumericalOverflow)?
+                .checked_div(10000)
+                .ok_or(AuctionHouseError::NumericalOverflow)? as u64
+        } else {
+            // Leftover amount due to imprecision given to last recipient
+            remaining_amount
+        };
+        remaining_amount = remaining_amount
+            .checked_sub(recipient_amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+        recipient_amounts.push((recipient.address, recipient_amount));
+    }
+
+    Ok(recipient_amounts)
+}
+
+/**
+ * Finds the RevenueSplit of the given mint in remaining_accounts.
+ */
+pub fn get_revenue_split_from_remaining_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    auction_house: &Pubkey,
+    mint: &Pubkey,
+) -> Result<anchor_lang::prelude::Account<'info, RevenueSplit>> {
+    let (revenue_split_key, _) = Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            auction_house.as_ref(),
+            mint.as_ref(),
+            REVENUE_SPLIT.as_bytes(),
+        ],
+        program_id,
+    );
+    let revenue_split_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == revenue_split_key)
+        .ok_or(AuctionHouseError::RevenueSplitNotProvided)?;
+
+    anchor_lang::prelude::Account::<RevenueSplit>::try_from(revenue_split_info)
+}
+
+/**
+ * Finds the wallet or token account of a revenue split or treasury split recipient in
+ * remaining_accounts.
+ */
+pub fn find_revenue_split_recipient_account<'a, 'b>(
+    remaining_accounts: &'b [AccountInfo<'a>],
+    key: &Pubkey,
+) -> Result<&'b AccountInfo<'a>> {
+    remaining_accounts
+        .iter()
+        .find(|account| account.key == key)
+        .ok_or(AuctionHouseError::RevenueSplitRecipientNotProvided.into())
+}
+
+/**
+ * Basically the same as split_primary_sale_between_creators_native and
+ * split_primary_sale_between_creators_non_native, but pays the recipients of a revenue split.
+ *
+ * Each recipient must be in remaining_accounts, followed by its associated token account
+ * for non-native payments. Unlike the creators they can be in any order.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn pay_revenue_split<'a>(
+    remaining_accounts: &[AccountInfo<'a>],
+    revenue_split: &RevenueSplit,
+    payer_account: &AccountInfo<'a>,
+    payment_account_owner: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    treasury_mint: &AccountInfo<'a>,
+    ata_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+    fee_payer_seeds: &[&[u8]],
+    total_amount: u64,
+    is_native: bool,
+) -> Result<()> {
+    for (recipient, amount) in get_revenue_split_amounts(&revenue_split.recipients, total_amount)? {
+        if amount == 0 {
+            continue;
+        }
+
+        let recipient_info = find_revenue_split_recipient_account(remaining_accounts, &recipient)?;
+        if is_native {
+            invoke_signed(
+                &system_instruction::transfer(payer_account.key, recipient_info.key, amount),
+                &[
+                    payer_account.clone(),
+                    recipient_info.clone(),
+                    system_program.clone(),
+                ],
+                &[signer_seeds],
+            )?;
+            continue;
+        }
+
+        let recipient_token_account_info = find_revenue_split_recipient_account(
+            remaining_accounts,
+            &get_associated_token_address(&recipient, treasury_mint.key),
+        )?;
+        if recipient_token_account_info.data_is_empty() {
+            make_ata(
+                recipient_token_account_info.to_account_info(),
+                recipient_info.to_account_info(),
+                treasury_mint.to_account_info(),
+                fee_payer.to_account_info(),
+                ata_program.to_account_info(),
+                token_program.to_account_info(),
+                system_program.to_account_info(),
+                rent.to_account_info(),
+                fee_payer_seeds,
+            )?;
+        }
+        assert_is_ata(recipient_token_account_info, &recipient, treasury_mint.key)?;
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                payer_account.key,
+                recipient_token_account_info.key,
+                payment_account_owner.key,
+                &[],
+                amount,
+            )?,
+            &[
+                payer_account.clone(),
+                recipient_token_account_info.clone(),
+                token_program.clone(),
+                payment_account_owner.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+/**
+ * The commitment a bidder submits with commit_sealed_bid. Including the bidder prevents
+ * other bidders from copying a commitment and revealing it once the bid is known.
+ */
+pub fn get_sealed_bid_commitment(price: u64, salt: &[u8; 32], bidder: &Pubkey) -> [u8; 32] {
+    solana_program::keccak::hashv(&[&price.to_le_bytes(), salt, bidder.as_ref()]).0
+}
+
+/**
+ * Updates the highest and second highest bids with a revealed bid. For equal bids, the
+ * bid which was revealed first wins.
+ */
+pub fn record_sealed_bid_reveal(
+    sealed_bid_auction: &mut SealedBidAuction,
+    bidder: &Pubkey,
+    price: u64,
+) {
+    if sealed_bid_auction.highest_bidder.is_none() || price > sealed_bid_auction.highest_bid {
+        sealed_bid_auction.second_highest_bid = sealed_bid_auction.highest_bid;
+        sealed_bid_auction.highest_bid = price;
+        sealed_bid_auction.highest_bidder = Some(*bidder);
+    } else if price > sealed_bid_auction.second_highest_bid {
+        sealed_bid_auction.second_highest_bid = price;
+    }
+}
+
+/**
+ * The price the winner of a sealed bid auction pays.
+ */
+pub fn get_sealed_bid_clearing_price(sealed_bid_auction: &SealedBidAuction) -> u64 {
+    if sealed_bid_auction.is_second_price {
+        std::cmp::max(
+            sealed_bid_auction.second_highest_bid,
+            sealed_bid_auction.reserve_price,
+        )
+    } else {
+        sealed_bid_auction.highest_bid
+    }
+}
+
+pub fn assert_sealed_bid_auction_winner(
+    sealed_bid_auction: &SealedBidAuction,
+    buyer: &Pubkey,
+    buyer_price: u64,
+    unix_timestamp: i64,
+) -> Result<()> {
+    if unix_timestamp < sealed_bid_auction.reveal_end_time {
+        return Err(AuctionHouseError::SealedBidAuctionRevealNotEnded.into());
+    }
+
+    if sealed_bid_auction.is_settled {
+        return Err(AuctionHouseError::SealedBidAuctionAlreadySettled.into());
+    }
+
+    if sealed_bid_auction.highest_bidder != Some(*buyer) {
+        return Err(AuctionHouseError::SealedBidAuctionWinnerMismatch.into());
+    }
+
+    if buyer_price != get_sealed_bid_clearing_price(sealed_bid_auction) {
+        return Err(AuctionHouseError::MismatchedPrices.into());
+    }
+
+    Ok(())
+}
+
+/**
+ * Balance of a sealed bid escrow, which is a token account for SPL auction houses and a
+ * system account otherwise.
+ */
+pub fn get_sealed_bid_escrow_balance(sealed_bid_escrow: &AccountInfo) -> Result<u64> {
+    if *sealed_bid_escrow.owner == spl_token::id() {
+        return Ok(Account::unpack(&sealed_bid_escrow.data.borrow())?.amount);
+    }
+
+    Ok(sealed_bid_escrow.lamports())
+}
+
+/**
+ * Moves funds out of a sealed bid escrow. Native escrows sign with their own seeds, token
+ * escrows are owned by the auction house, like escrow payment accounts.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_sealed_bid_escrow<'info>(
+    sealed_bid_escrow: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    auction_house: &anchor_lang::prelude::Account<'info, AuctionHouse>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    sealed_bid: &Pubkey,
+    escrow_bump: u8,
+    amount: u64,
+    is_native: bool,
+) -> Result<()> {
+    let auction_house_key = auction_house.key();
+
+    if is_native {
+        let escrow_signer_seeds = [
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            sealed_bid.as_ref(),
+            SEALED_BID_ESCROW.as_bytes(),
+            &[escrow_bump],
+        ];
+        invoke_signed(
+            &system_instruction::transfer(sealed_bid_escrow.key, destination.key, amount),
+            &[
+                sealed_bid_escrow.clone(),
+                destination.clone(),
+                system_program.clone(),
+            ],
+            &[&escrow_signer_seeds],
+        )?;
+    } else {
+        let ah_seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            &[auction_house.bump],
+        ];
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                sealed_bid_escrow.key,
+                destination.key,
+                &auction_house_key,
+                &[],
+                amount,
+            )?,
+            &[
+                sealed_bid_escrow.clone(),
+                destination.clone(),
+                token_program.clone(),
+                auction_house.to_account_info(),
+            ],
+            &[&ah_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+/**
+ * Moves the clearing price of a sealed bid auction from the winner's sealed bid escrow to
+ * their escrow payment account, which execute_sale_v2 then pays out of. The SealedBid and
+ * its escrow are passed in remaining_accounts, next to the SealedBidAuction.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn fund_escrow_payment_account_from_sealed_bid<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    auction_house: &anchor_lang::prelude::Account<'info, AuctionHouse>,
+    sealed_bid_auction: &Pubkey,
+    buyer: &Pubkey,
+    escrow_payment_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+    is_native: bool,
+) -> Result<()> {
+    let (sealed_bid_key, _) = Pubkey::find_program_address(
+        &[
+            SEALED_BID.as_bytes(),
+            sealed_bid_auction.as_ref(),
+            buyer.as_ref(),
+        ],
+        program_id,
+    );
+    let sealed_bid_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == sealed_bid_key)
+        .ok_or(AuctionHouseError::SealedBidNotProvided)?;
+    let sealed_bid = anchor_lang::prelude::Account::<SealedBid>::try_from(sealed_bid_info)?;
+
+    let auction_house_key = auction_house.key();
+    let sealed_bid_escrow_key = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            sealed_bid_key.as_ref(),
+            SEALED_BID_ESCROW.as_bytes(),
+            &[sealed_bid.escrow_bump],
+        ],
+        program_id,
+    )
+    .map_err(|_| AuctionHouseError::SealedBidNotProvided)?;
+    let sealed_bid_escrow = remaining_accounts
+        .iter()
+        .find(|account| account.key() == sealed_bid_escrow_key)
+        .ok_or(AuctionHouseError::SealedBidNotProvided)?;
+
+    transfer_from_sealed_bid_escrow(
+        sealed_bid_escrow,
+        escrow_payment_account,
+        auction_house,
+        token_program,
+        system_program,
+        &sealed_bid_key,
+        sealed_bid.escrow_bump,
+        amount,
+        is_native,
+    )
+}
+
+/**
+ * The LastBidPrice fields which admin overrides can change, see LastBidPriceAuditLog.
+ */
+pub fn get_last_bid_price_snapshot(last_bid_price: &LastBidPrice) -> LastBidPriceSnapshot {
+    LastBidPriceSnapshot {
+        price: last_bid_price.price,
+        bidder: last_bid_price.bidder.unwrap_or(ZERO_PUBKEY),
+        has_been_sold: last_bid_price.has_been_sold,
+    }
+}
+
+pub fn get_last_bid_price_audit_log_size(number_of_entries: usize) -> usize {
+    LAST_BID_PRICE_AUDIT_LOG_SIZE + number_of_entries * LAST_BID_PRICE_OVERRIDE_SIZE
+}
+
+/**
+ * Records an admin override of a LastBidPrice, after it has been applied. Emits a
+ * LastBidPriceOverriddenEvent, and appends to the mint's LastBidPriceAuditLog if the
+ * LastBidPrice has one, in which case the log must be passed in remaining_accounts.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn record_last_bid_price_override<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    auction_house: &Pubkey,
+    mint: &Pubkey,
+    last_bid_price: &anchor_lang::prelude::Account<'info, LastBidPrice>,
+    kind: LastBidPriceOverrideKind,
+    signer: &Pubkey,
+    old: LastBidPriceSnapshot,
+) -> Result<()> {
+    let entry = LastBidPriceOverride {
+        kind,
+        signer: *signer,
+        unix_timestamp: Clock::get()?.unix_timestamp,
+        old,
+        new: get_last_bid_price_snapshot(last_bid_price),
+    };
+
+    emit!(LastBidPriceOverriddenEvent {
+        last_bid_price: last_bid_price.key(),
+        mint: *mint,
+        kind: entry.kind,
+        signer: entry.signer,
+        unix_timestamp: entry.unix_timestamp,
+        old: entry.old,
+        new: entry.new,
+    });
+
+    if !last_bid_price.has_audit_log {
+        return Ok(());
+    }
+
+    let (audit_log_key, _) = Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            auction_house.as_ref(),
+            mint.as_ref(),
+            LAST_BID_PRICE_AUDIT_LOG.as_bytes(),
+        ],
+        program_id,
+    );
+    let audit_log_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == audit_log_key)
+        .ok_or(AuctionHouseError::LastBidPriceAuditLogNotProvided)?;
+    if audit_log_info.owner != program_id {
+        return Err(AuctionHouseError::IncorrectOwner.into());
+    }
+    let number_of_entries = {
+        let data = audit_log_info.try_borrow_data()?;
+        if data.len() < LAST_BID_PRICE_AUDIT_LOG_SIZE
+            || data[..8] != <LastBidPriceAuditLog as anchor_lang::Discriminator>::discriminator()
+        {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        u32::from_le_bytes(*array_ref![data, LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET, 4])
+    };
+
+    // The log pays for its own growth, so overrides don't need a payer.
+    let new_size = get_last_bid_price_audit_log_size(number_of_entries as usize + 1);
+    if audit_log_info.lamports() < Rent::get()?.minimum_balance(new_size) {
+        return Err(AuctionHouseError::LastBidPriceAuditLogNeedsFunding.into());
+    }
+    audit_log_info.realloc(new_size, false)?;
+
+    // Entries have a fixed size, so the new one goes right after the existing ones and the
+    // log never has to be deserialized.
+    let entry_offset = LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET
+        + 4
+        + number_of_entries as usize * LAST_BID_PRICE_OVERRIDE_SIZE;
+    let mut data = audit_log_info.try_borrow_mut_data()?;
+    data[entry_offset..entry_offset + LAST_BID_PRICE_OVERRIDE_SIZE]
+        .copy_from_slice(&entry.try_to_vec()?);
+    data[LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET..LAST_BID_PRICE_AUDIT_LOG_ENTRIES_OFFSET + 4]
+        .copy_from_slice(
+            &number_of_entries
+                .checked_add(1)
+                .ok_or(AuctionHouseError::NumericalOverflow)?
+                .to_le_bytes(),
+        );
+
+    Ok(())
+}
+
+/**
+ * Finds the SealedBidAuction of the given mint in remaining_accounts. Callers must call
+ * exit on the returned account to persist any changes.
+ */
+pub fn get_sealed_bid_auction_from_remaining_accounts<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+) -> Result<anchor_lang::prelude::Account<'info, SealedBidAuction>> {
+    let (sealed_bid_auction_key, _) = Pubkey::find_program_address(
+        &[
+            SEALED_BID_AUCTION.as_bytes(),
+            auction_house.as_ref(),
+            token_mint.as_ref(),
+        ],
+        program_id,
+    );
+    let sealed_bid_auction_info = remaining_accounts
+        .iter()
+        .find(|account| account.key() == sealed_bid_auction_key)
+        .ok_or(AuctionHouseError::SealedBidAuctionNotProvided)?;
+
+    anchor_lang::prelude::Account::<SealedBidAuction>::try_from(sealed_bid_auction_info)
+}
+
+/**
+ * For now, we allow end_time = None
+ *
+ * We may want to revisit this in the future. But product-wise, it seems
+ * like we may want to have an open edition that never ends.
+ */
+pub fn assert_valid_edition_sale_times(
+    allowlist_sale_start_time: Option<i64>,
+    public_sale_start_time: i64,
+    sale_end_time: Option<i64>,
+    unix_timestamp: i64,
+) -> Result<()> {
+    // Allowlist sale is enabled
+    if let Some(allowlist_sale_start_time_val) = allowlist_sale_start_time {
+        // Note: a 0 public_sale_start_time represents an allowlist sale with
+        // no public sale.
+        if public_sale_start_time != 0 && allowlist_sale_start_time_val >= public_sale_start_time {
+            return Err(AuctionHouseError::AllowlistSaleMustBeBeforePublicSale.into());
+        }
+    }
+
+    // Because public_sale_start_time of 0 signifies an allowlist sale with NO
+    // public sale, it can only be 0 if an allowlist_sale_start_time is provided.
+    if public_sale_start_time == 0 && allowlist_sale_start_time.is_none() {
+        return Err(AuctionHouseError::PublicSaleStartTimeCannotBeZero.into());
+    }
+
+    if let Some(sale_end_time_val) = sale_end_time {
+        // Validate sale_end_time by itself
+        if unix_timestamp >= sale_end_time_val {
+            return Err(AuctionHouseError::EndTimeMustBeInFuture.into());
+        }
+
+        // Validate both start_time and sale_end_time
+        if public_sale_start_time >= sale_end_time_val {
+            return Err(AuctionHouseError::EndTimeMustComeAfterStartTime.into());
+        }
+
+        // Validate allowlist sale time is not after sale_end_time
+        if let Some(allowlist_sale_start_time_val) = allowlist_sale_start_time {
+            if allowlist_sale_start_time_val >= sale_end_time_val {
+                return Err(AuctionHouseError::AllowlistSaleCannotBeAfterEndTime.into());
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn assert_valid_times_for_buy_edition(
+    allowlist_sale_start_time: Option<i64>,
+    public_sale_start_time: i64,
+    sale_end_time: Option<i64>,
+    unix_timestamp: i64,
+) -> Result<()> {
+    if let Some(allowlist_sale_start_time) = allowlist_sale_start_time {
+        if unix_timestamp < allowlist_sale_start_time {
+            return Err(AuctionHouseError::BuyEditionTooEarly.into());
+        }
+
+        if is_during_allowlist_sale(
+            Some(allowlist_sale_start_time),
             public_sale_start_time,
             unix_timestamp,
         ) {
@@ -1156,80 +2542,444 @@ pub fn assert_valid_times_for_buy_edition(
         }
     }
 
-    if unix_timestamp < public_sale_start_time {
-        return Err(AuctionHouseError::BuyEditionTooEarly.into());
+    if unix_timestamp < public_sale_start_time {
+        return Err(AuctionHouseError::BuyEditionTooEarly.into());
+    }
+
+    if let Some(end_time_val) = sale_end_time {
+        if unix_timestamp > end_time_val {
+            return Err(AuctionHouseError::BuyEditionTooLate.into());
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn assert_valid_treasury_mint_for_buy_edition(
+    edition_distributor_treasury_mint: Pubkey,
+    treasury_mint: Pubkey,
+) -> Result<()> {
+    if edition_distributor_treasury_mint == ZERO_PUBKEY {
+        // For legacy edition distributors, only SOL was supported so we check
+        // that the provided treasury mint is the SOL mint
+        if treasury_mint != spl_token::native_mint::id() {
+            return Err(AuctionHouseError::InvalidTreasuryMintForBuyEdition.into());
+        }
+
+        return Ok(());
+    }
+
+    if treasury_mint != edition_distributor_treasury_mint {
+        return Err(AuctionHouseError::InvalidTreasuryMintForBuyEdition.into());
+    }
+
+    return Ok(());
+}
+
+pub fn assert_edition_supply_available(
+    max_editions_to_sell: Option<u64>,
+    is_supply_locked: bool,
+    edition: u64,
+) -> Result<()> {
+    if is_supply_locked {
+        return Err(AuctionHouseError::EditionSupplyLocked.into());
+    }
+
+    if let Some(max_editions_to_sell_val) = max_editions_to_sell {
+        if edition > max_editions_to_sell_val {
+            return Err(AuctionHouseError::EditionSupplyCapReached.into());
+        }
+    }
+
+    return Ok(());
+}
+
+/**
+ * The supply of an edition distributor can only be locked once the sale is over,
+ * i.e. the sale end time has passed or every edition under the cap has been minted.
+ */
+pub fn can_lock_edition_supply(
+    sale_end_time: Option<i64>,
+    max_editions_to_sell: Option<u64>,
+    supply: u64,
+    unix_timestamp: i64,
+) -> bool {
+    let has_sale_ended = match sale_end_time {
+        Some(sale_end_time_val) => unix_timestamp > sale_end_time_val,
+        None => false,
+    };
+
+    let has_sold_out = match max_editions_to_sell {
+        Some(max_editions_to_sell_val) => supply >= max_editions_to_sell_val,
+        None => false,
+    };
+
+    has_sale_ended || has_sold_out
+}
+
+/**
+ * Verifies the master edition PDA for the given mint and returns its current supply.
+ */
+pub fn get_master_edition_supply(
+    master_edition: &AccountInfo,
+    mint: &Pubkey,
+    edition_bump: u8,
+) -> Result<u64> {
+    let derivation_result = assert_pda_derivation(
+        &mpl_token_metadata::id(),
+        master_edition,
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            mint.as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+            &[edition_bump],
+        ],
+    );
+
+    if let Err(_e) = derivation_result {
+        return Err(AuctionHouseError::InvalidMasterEditionAccount.into());
+    }
+
+    let master_edition = mpl_token_metadata::state::get_master_edition(master_edition)?;
+    Ok((*master_edition).supply())
+}
+
+pub fn is_during_allowlist_sale(
+    allowlist_sale_start_time: Option<i64>,
+    public_sale_start_time: i64,
+    unix_timestamp: i64,
+) -> bool {
+    if allowlist_sale_start_time.is_none() {
+        return false;
+    }
+
+    let allowlist_sale_start_time = allowlist_sale_start_time.unwrap();
+    if allowlist_sale_start_time > unix_timestamp {
+        return false;
+    }
+
+    if unix_timestamp >= public_sale_start_time && public_sale_start_time != 0 {
+        return false;
+    }
+
+    true
+}
+
+/**
+ * Returns the amount taken from the suspected bot. This is capped at the suspect's balance.
+ */
+pub fn punish_bots<'a>(
+    error: &Error,
+    suspect_bot_account: AccountInfo<'a>,
+    payment_account: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    bot_tax_amount: Option<u64>,
+) -> Result<u64> {
+    let bot_fee = bot_tax_amount.unwrap_or(BOT_FEE);
+    let bot_tax_collected_error: Result<()> = Err(AuctionHouseError::BotTaxCollected.into());
+    msg!(
+        "{}, {}, AuctionHouse botting is taxed at {:?} lamports",
+        bot_tax_collected_error.unwrap_err().to_string(),
+        error.to_string(),
+        bot_fee
+    );
+
+    let final_fee = bot_fee.min(suspect_bot_account.lamports());
+    invoke(
+        &system_instruction::transfer(suspect_bot_account.key, payment_account.key, final_fee),
+        &[suspect_bot_account, payment_account, system_program],
+    )?;
+    Ok(final_fee)
+}
+
+/**
+ * Same as punish_bots, but the tax is paid in an SPL token from the suspect's token account,
+ * which must have been checked with get_bot_tax_token_account. Returns the amount taken,
+ * which is capped at the token account balance.
+ */
+pub fn punish_bots_non_native<'a>(
+    error: &Error,
+    suspect_bot_account: AccountInfo<'a>,
+    suspect_bot_token_account: AccountInfo<'a>,
+    suspect_bot_token_balance: u64,
+    payment_token_account: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    bot_tax_amount: u64,
+) -> Result<u64> {
+    let bot_tax_collected_error: Result<()> = Err(AuctionHouseError::BotTaxCollected.into());
+    msg!(
+        "{}, {}, AuctionHouse botting is taxed at {:?} tokens",
+        bot_tax_collected_error.unwrap_err().to_string(),
+        error.to_string(),
+        bot_tax_amount
+    );
+
+    let final_fee = bot_tax_amount.min(suspect_bot_token_balance);
+    if final_fee == 0 {
+        return Ok(0);
+    }
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            suspect_bot_token_account.key,
+            payment_token_account.key,
+            suspect_bot_account.key,
+            &[],
+            final_fee,
+        )?,
+        &[
+            suspect_bot_token_account,
+            payment_token_account,
+            token_program,
+            suspect_bot_account,
+        ],
+    )?;
+    Ok(final_fee)
+}
+
+/**
+ * Returns the token account if it can send or receive an SPL bot tax, i.e. it is an unfrozen
+ * token account of the given mint (owned by owner, if given). Bots control which accounts
+ * are passed, so the tax falls back to other accounts instead of failing on invalid ones.
+ */
+pub fn get_bot_tax_token_account(
+    token_account: &AccountInfo,
+    mint: &Pubkey,
+    owner: Option<&Pubkey>,
+) -> Option<Account> {
+    if *token_account.owner != spl_token::id() {
+        return None;
+    }
+    let token_account = Account::unpack(&token_account.data.borrow()).ok()?;
+    if token_account.mint != *mint
+        || token_account.is_frozen()
+        || owner.map_or(false, |owner| token_account.owner != *owner)
+    {
+        return None;
+    }
+
+    Some(token_account)
+}
+
+/**
+ * The bot tax goes to the configured recipient if it is passed in the remaining accounts,
+ * and to the fallback otherwise. Failing instead would let bots dodge the tax by leaving the
+ * recipient out.
+ */
+pub fn get_bot_tax_recipient<'a>(
+    bot_tax_recipient: Option<Pubkey>,
+    remaining_accounts: &[AccountInfo<'a>],
+    fallback: AccountInfo<'a>,
+) -> AccountInfo<'a> {
+    bot_tax_recipient
+        .and_then(|bot_tax_recipient_val| {
+            remaining_accounts
+                .iter()
+                .find(|account| *account.key == bot_tax_recipient_val)
+                .cloned()
+        })
+        .unwrap_or(fallback)
+}
+
+/**
+ * Transfers the full balance of a token account owned by a program PDA to the receiver,
+ * and optionally closes the emptied token account with the rent going to rent_receiver.
+ */
+pub fn sweep_token_account<'a>(
+    token_account: &anchor_lang::prelude::Account<'a, TokenAccount>,
+    token_receiver: AccountInfo<'a>,
+    rent_receiver: AccountInfo<'a>,
+    token_account_owner: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+    close_token_account: bool,
+) -> Result<()> {
+    if token_account.amount > 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                token_program.clone(),
+                anchor_spl::token::Transfer {
+                    from: token_account.to_account_info(),
+                    to: token_receiver,
+                    authority: token_account_owner.clone(),
+                },
+            )
+            .with_signer(&[signer_seeds]),
+            token_account.amount,
+        )?;
+    }
+
+    if close_token_account {
+        anchor_spl::token::close_account(
+            CpiContext::new(
+                token_program,
+                anchor_spl::token::CloseAccount {
+                    account: token_account.to_account_info(),
+                    destination: rent_receiver,
+                    authority: token_account_owner,
+                },
+            )
+            .with_signer(&[signer_seeds]),
+        )?;
     }
 
-    if let Some(end_time_val) = sale_end_time {
-        if unix_timestamp > end_time_val {
-            return Err(AuctionHouseError::BuyEditionTooLate.into());
-        }
-    }
+    msg!(
+        "Swept {} tokens of mint {} from {}",
+        token_account.amount,
+        token_account.mint,
+        token_account.key()
+    );
 
-    return Ok(());
+    Ok(())
 }
 
-pub fn assert_valid_treasury_mint_for_buy_edition(
-    edition_distributor_treasury_mint: Pubkey,
-    treasury_mint: Pubkey,
+/**
+ * Grows a program account to the given size if it is smaller, zeroing the new bytes.
+ * The payer covers any additional rent.
+ */
+pub fn realloc_account_if_needed<'a>(
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_size: usize,
 ) -> Result<()> {
-    if edition_distributor_treasury_mint == ZERO_PUBKEY {
-        // For legacy edition distributors, only SOL was supported so we check
-        // that the provided treasury mint is the SOL mint
-        if treasury_mint != spl_token::native_mint::id() {
-            return Err(AuctionHouseError::InvalidTreasuryMintForBuyEdition.into());
-        }
-
+    let current_size = account.data_len();
+    if current_size >= new_size {
         return Ok(());
     }
 
-    if treasury_mint != edition_distributor_treasury_mint {
-        return Err(AuctionHouseError::InvalidTreasuryMintForBuyEdition.into());
+    let rent = Rent::get()?;
+    let required_lamports = rent
+        .minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, required_lamports),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
     }
 
-    return Ok(());
+    account.realloc(new_size, true)?;
+
+    msg!(
+        "Reallocated account {} from {} to {} bytes",
+        account.key,
+        current_size,
+        new_size
+    );
+
+    Ok(())
 }
 
-pub fn is_during_allowlist_sale(
-    allowlist_sale_start_time: Option<i64>,
-    public_sale_start_time: i64,
-    unix_timestamp: i64,
-) -> bool {
-    if allowlist_sale_start_time.is_none() {
-        return false;
+/**
+ * Migrates a program account to its latest layout. The account is grown to new_size if
+ * needed (new fields are zeroed, i.e. None/0/false), then deserialized so update can set
+ * the account version and any other fields, and serialized again.
+ */
+pub fn migrate_program_account<'a, T>(
+    program_id: &Pubkey,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_size: usize,
+    update: impl FnOnce(&mut T),
+) -> Result<()>
+where
+    T: AccountSerialize
+        + AccountDeserialize
+        + anchor_lang::Owner
+        + anchor_lang::Discriminator
+        + Clone,
+{
+    if account.owner != program_id {
+        return Err(AuctionHouseError::IncorrectOwner.into());
     }
 
-    let allowlist_sale_start_time = allowlist_sale_start_time.unwrap();
-    if allowlist_sale_start_time > unix_timestamp {
-        return false;
+    {
+        let data = account.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != T::discriminator() {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
     }
 
-    if unix_timestamp >= public_sale_start_time && public_sale_start_time != 0 {
-        return false;
-    }
+    realloc_account_if_needed(account, payer, system_program, new_size)?;
 
-    true
+    let mut migrated_account: anchor_lang::prelude::Account<T> =
+        anchor_lang::prelude::Account::try_from(account)?;
+    update(&mut *migrated_account);
+    migrated_account.exit(program_id)?;
+
+    Ok(())
 }
 
-pub fn punish_bots<'a>(
-    error: Error,
-    suspect_bot_account: AccountInfo<'a>,
-    payment_account: AccountInfo<'a>,
-    system_program: AccountInfo<'a>,
+/**
+ * Migrates an EditionAllowlistSettings account. Legacy accounts have no version, which comes
+ * before merkle_roots, so they are grown by a byte and shifted to make room for it first.
+ */
+pub fn migrate_edition_allowlist_settings<'a>(
+    program_id: &Pubkey,
+    account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
 ) -> Result<()> {
-    let bot_tax_collected_error: Result<()> = Err(AuctionHouseError::BotTaxCollected.into());
-    msg!(
-        "{}, {}, AuctionHouse botting is taxed at {:?} lamports",
-        bot_tax_collected_error.unwrap_err().to_string(),
-        error.to_string(),
-        BOT_FEE
+    if account.owner != program_id {
+        return Err(AuctionHouseError::IncorrectOwner.into());
+    }
+
+    if account.data_len() == LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE {
+        {
+            let data = account.try_borrow_data()?;
+            if data[..8]
+                != <EditionAllowlistSettings as anchor_lang::Discriminator>::discriminator()
+            {
+                return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+            }
+        }
+
+        realloc_account_if_needed(
+            account,
+            payer,
+            system_program,
+            EDITION_ALLOWLIST_SETTINGS_SIZE,
+        )?;
+        shift_legacy_edition_allowlist_settings(&mut account.try_borrow_mut_data()?);
+    }
+
+    migrate_program_account::<EditionAllowlistSettings>(
+        program_id,
+        account,
+        payer,
+        system_program,
+        EDITION_ALLOWLIST_SETTINGS_SIZE,
+        |edition_allowlist_settings| {
+            edition_allowlist_settings.version = EDITION_ALLOWLIST_SETTINGS_VERSION
+        },
+    )
+}
+
+/**
+ * Moves everything after the bump of a legacy EditionAllowlistSettings account one byte
+ * ahead, leaving a zero version in between. data must already have the current size.
+ */
+pub fn shift_legacy_edition_allowlist_settings(data: &mut [u8]) {
+    data.copy_within(
+        EDITION_ALLOWLIST_SETTINGS_VERSION_OFFSET..LEGACY_EDITION_ALLOWLIST_SETTINGS_SIZE,
+        EDITION_ALLOWLIST_SETTINGS_VERSION_OFFSET + 1,
     );
+    data[EDITION_ALLOWLIST_SETTINGS_VERSION_OFFSET] = 0;
+}
+
+/**
+ * EditionAllowlistSettings accounts created before versioning was added can't be read until
+ * they are migrated, see migrate_edition_allowlist_settings.
+ */
+pub fn assert_edition_allowlist_settings_migrated(account: &AccountInfo) -> Result<()> {
+    if account.data_len() < EDITION_ALLOWLIST_SETTINGS_SIZE {
+        return Err(AuctionHouseError::AccountNeedsMigration.into());
+    }
 
-    let final_fee = BOT_FEE.min(suspect_bot_account.lamports());
-    invoke(
-        &system_instruction::transfer(suspect_bot_account.key, payment_account.key, final_fee),
-        &[suspect_bot_account, payment_account, system_program],
-    )?;
     Ok(())
 }
 
@@ -1360,6 +3110,129 @@ mod tests {
         assert_eq!(constant_result.unwrap(), tick_size_constant_in_lamports);
     }
 
+    #[test]
+    fn get_tick_size_in_lamports_test() {
+        let one_sol = 1_000_000_000;
+        let get_tick_size = |constant_in_lamports, percent, min_in_lamports, max_in_lamports| {
+            TickSize {
+                constant_in_lamports,
+                percent,
+                min_in_lamports,
+                max_in_lamports,
+            }
+        };
+
+        // Falls back to the default of 10% rounded down to 0.1 SOL.
+        assert_eq!(
+            get_tick_size_in_lamports(one_sol * 5, &TickSize::default(), 9).unwrap(),
+            one_sol / 2
+        );
+        assert_eq!(
+            get_tick_size_in_lamports(1234, &get_tick_size(5, 0, 0, 0), 9).unwrap(),
+            5
+        );
+
+        // Percent of the price, at least 1 lamport.
+        assert_eq!(
+            get_tick_size_in_lamports(1000, &get_tick_size(0, 5, 0, 0), 9).unwrap(),
+            50
+        );
+        assert_eq!(
+            get_tick_size_in_lamports(10, &get_tick_size(0, 5, 0, 0), 9).unwrap(),
+            1
+        );
+        assert_eq!(
+            get_tick_size_in_lamports(u64::MAX, &get_tick_size(0, 100, 0, 0), 9).unwrap(),
+            u64::MAX
+        );
+
+        // Clamped to the min and max.
+        assert_eq!(
+            get_tick_size_in_lamports(1000, &get_tick_size(0, 5, 60, 0), 9).unwrap(),
+            60
+        );
+        assert_eq!(
+            get_tick_size_in_lamports(1000, &get_tick_size(0, 5, 0, 40), 9).unwrap(),
+            40
+        );
+        assert_eq!(
+            get_tick_size_in_lamports(one_sol * 5, &get_tick_size(0, 0, 0, 100), 9).unwrap(),
+            100
+        );
+    }
+
+    #[test]
+    fn get_effective_tick_size_test() {
+        let default_tick_size = TickSize {
+            percent: 5,
+            ..TickSize::default()
+        };
+        let mut last_bid_price = get_auction_last_bid_price(0, None, 0, None);
+        assert_eq!(
+            get_effective_tick_size(&last_bid_price, &default_tick_size),
+            default_tick_size
+        );
+
+        last_bid_price.tick_size_max_in_lamports = 100;
+        assert_eq!(
+            get_effective_tick_size(&last_bid_price, &default_tick_size),
+            TickSize {
+                max_in_lamports: 100,
+                ..TickSize::default()
+            }
+        );
+    }
+
+    #[test]
+    fn assert_valid_tick_size_test() {
+        let tick_size = TickSize::default();
+        assert!(assert_valid_tick_size(&tick_size, 9).is_ok());
+
+        let valid_tick_sizes = [
+            TickSize {
+                constant_in_lamports: 100_000_000,
+                ..tick_size
+            },
+            TickSize {
+                percent: 100,
+                min_in_lamports: 10,
+                max_in_lamports: 10,
+                ..tick_size
+            },
+            TickSize {
+                min_in_lamports: 10,
+                ..tick_size
+            },
+        ];
+        for valid_tick_size in valid_tick_sizes {
+            assert!(assert_valid_tick_size(&valid_tick_size, 9).is_ok());
+        }
+
+        let invalid_tick_sizes = [
+            TickSize {
+                constant_in_lamports: 1,
+                ..tick_size
+            },
+            TickSize {
+                percent: 101,
+                ..tick_size
+            },
+            TickSize {
+                constant_in_lamports: 100_000_000,
+                percent: 5,
+                ..tick_size
+            },
+            TickSize {
+                min_in_lamports: 11,
+                max_in_lamports: 10,
+                ..tick_size
+            },
+        ];
+        for invalid_tick_size in invalid_tick_sizes {
+            assert!(assert_valid_tick_size(&invalid_tick_size, 9).is_err());
+        }
+    }
+
     fn get_metadata(creator_pubkeys: Option<Vec<Pubkey>>, primary_sale_happened: bool) -> Metadata {
         let creators = match creator_pubkeys {
             Some(creator_pubkeys_some) => Some(
@@ -1423,6 +3296,17 @@ mod tests {
                     has_been_sold: 0,
                     tick_size_constant_in_lamports: 0,
                     has_campaign_escrow_treasury: false,
+                    rent_payer: None,
+                    version: 0,
+                    bidder_uses_shared_escrow: false,
+                    buy_now_price: None,
+                    proxy_max_bid: 0,
+                    tick_size_percent: 0,
+                    tick_size_min_in_lamports: 0,
+                    tick_size_max_in_lamports: 0,
+                    has_revenue_split: false,
+                    buyer_premium_basis_points: 0,
+                    has_audit_log: false,
                 }),
             },
             GetHasBeenSoldTestCase {
@@ -1434,6 +3318,17 @@ mod tests {
                     has_been_sold: 1,
                     tick_size_constant_in_lamports: 0,
                     has_campaign_escrow_treasury: false,
+                    rent_payer: None,
+                    version: 0,
+                    bidder_uses_shared_escrow: false,
+                    buy_now_price: None,
+                    proxy_max_bid: 0,
+                    tick_size_percent: 0,
+                    tick_size_min_in_lamports: 0,
+                    tick_size_max_in_lamports: 0,
+                    has_revenue_split: false,
+                    buyer_premium_basis_points: 0,
+                    has_audit_log: false,
                 }),
             },
             GetHasBeenSoldTestCase {
@@ -1450,6 +3345,17 @@ mod tests {
                     has_been_sold: 0,
                     tick_size_constant_in_lamports: 0,
                     has_campaign_escrow_treasury: false,
+                    rent_payer: None,
+                    version: 0,
+                    bidder_uses_shared_escrow: false,
+                    buy_now_price: None,
+                    proxy_max_bid: 0,
+                    tick_size_percent: 0,
+                    tick_size_min_in_lamports: 0,
+                    tick_size_max_in_lamports: 0,
+                    has_revenue_split: false,
+                    buyer_premium_basis_points: 0,
+                    has_audit_log: false,
                 }),
             },
             GetHasBeenSoldTestCase {
@@ -1461,6 +3367,17 @@ mod tests {
                     has_been_sold: 1,
                     tick_size_constant_in_lamports: 0,
                     has_campaign_escrow_treasury: false,
+                    rent_payer: None,
+                    version: 0,
+                    bidder_uses_shared_escrow: false,
+                    buy_now_price: None,
+                    proxy_max_bid: 0,
+                    tick_size_percent: 0,
+                    tick_size_min_in_lamports: 0,
+                    tick_size_max_in_lamports: 0,
+                    has_revenue_split: false,
+                    buyer_premium_basis_points: 0,
+                    has_audit_log: false,
                 }),
             },
         ];
@@ -1569,4 +3486,772 @@ mod tests {
         let result = assert_valid_edition_sale_times(None, 0, Some(2), 0);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn assert_edition_supply_available_test() {
+        // No cap.
+        assert!(assert_edition_supply_available(None, false, 1000).is_ok());
+
+        // Under and at the cap.
+        assert!(assert_edition_supply_available(Some(10), false, 1).is_ok());
+        assert!(assert_edition_supply_available(Some(10), false, 10).is_ok());
+
+        // Over the cap.
+        assert!(assert_edition_supply_available(Some(10), false, 11).is_err());
+
+        // Locked supply.
+        assert!(assert_edition_supply_available(None, true, 1).is_err());
+        assert!(assert_edition_supply_available(Some(10), true, 1).is_err());
+    }
+
+    #[test]
+    fn can_lock_edition_supply_test() {
+        // Open edition which has not ended yet.
+        assert!(!can_lock_edition_supply(None, None, 5, 10));
+        assert!(!can_lock_edition_supply(Some(20), None, 5, 10));
+
+        // Sale end time has passed.
+        assert!(can_lock_edition_supply(Some(5), None, 5, 10));
+
+        // Sold out before the end time.
+        assert!(can_lock_edition_supply(Some(20), Some(5), 5, 10));
+        assert!(!can_lock_edition_supply(Some(20), Some(6), 5, 10));
+    }
+
+    #[test]
+    fn assert_last_bid_price_closable_test() {
+        let last_bid_price = |price: u64, bidder: Option<Pubkey>, has_been_sold: u8| LastBidPrice {
+            price,
+            bidder,
+            has_been_sold,
+            tick_size_constant_in_lamports: 0,
+            has_campaign_escrow_treasury: false,
+            rent_payer: None,
+            version: 0,
+            bidder_uses_shared_escrow: false,
+            buy_now_price: None,
+            proxy_max_bid: 0,
+            tick_size_percent: 0,
+            tick_size_min_in_lamports: 0,
+            tick_size_max_in_lamports: 0,
+            has_revenue_split: false,
+            buyer_premium_basis_points: 0,
+            has_audit_log: false,
+        };
+
+        // No auction.
+        assert!(assert_last_bid_price_closable(&last_bid_price(0, None, 0), false).is_ok());
+        assert!(
+            assert_last_bid_price_closable(&last_bid_price(0, Some(ZERO_PUBKEY), 0), false).is_ok()
+        );
+
+        // Live auction.
+        assert!(assert_last_bid_price_closable(&last_bid_price(10, None, 0), false).is_err());
+        assert!(assert_last_bid_price_closable(
+            &last_bid_price(0, Some(Pubkey::new_unique()), 0),
+            false
+        )
+        .is_err());
+
+        // The primary sale is only recorded by the LastBidPrice account.
+        assert!(assert_last_bid_price_closable(&last_bid_price(0, None, 1), false).is_err());
+        assert!(assert_last_bid_price_closable(&last_bid_price(0, None, 1), true).is_ok());
+
+        // Settings which a recreated account would lose.
+        let with_settings = |update: fn(&mut LastBidPrice)| {
+            let mut last_bid_price = last_bid_price(0, None, 0);
+            update(&mut last_bid_price);
+            last_bid_price
+        };
+        assert!(assert_last_bid_price_closable(
+            &with_settings(|last_bid_price| last_bid_price.has_audit_log = true),
+            false
+        )
+        .is_err());
+        assert!(assert_last_bid_price_closable(
+            &with_settings(|last_bid_price| last_bid_price.has_revenue_split = true),
+            false
+        )
+        .is_err());
+        assert!(assert_last_bid_price_closable(
+            &with_settings(|last_bid_price| last_bid_price.tick_size_constant_in_lamports = 1),
+            false
+        )
+        .is_err());
+        assert!(assert_last_bid_price_closable(
+            &with_settings(|last_bid_price| last_bid_price.tick_size_percent = 1),
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn assert_valid_trade_state_sale_type_test() {
+        assert_eq!(
+            assert_valid_trade_state_sale_type(1).unwrap(),
+            TradeStateSaleType::Auction
+        );
+        assert_eq!(
+            assert_valid_trade_state_sale_type(2).unwrap(),
+            TradeStateSaleType::InstantSale
+        );
+        assert_eq!(
+            assert_valid_trade_state_sale_type(3).unwrap(),
+            TradeStateSaleType::Offer
+        );
+
+        assert_eq!(
+            assert_valid_trade_state_sale_type(4).unwrap(),
+            TradeStateSaleType::SealedBidAuction
+        );
+
+        assert!(assert_valid_trade_state_sale_type(0).is_err());
+        assert!(assert_valid_trade_state_sale_type(5).is_err());
+        assert!(assert_valid_trade_state_sale_type(u8::MAX).is_err());
+    }
+
+    #[test]
+    fn assert_trade_state_not_expired_test() {
+        let trade_state = |expiry: Option<i64>| TradeState {
+            expiry,
+            ..Default::default()
+        };
+
+        assert!(assert_trade_state_not_expired(&trade_state(None), 100).is_ok());
+        assert!(assert_trade_state_not_expired(&trade_state(Some(101)), 100).is_ok());
+        assert!(assert_trade_state_not_expired(&trade_state(Some(100)), 100).is_err());
+        assert!(assert_trade_state_not_expired(&trade_state(Some(99)), 100).is_err());
+    }
+
+    #[test]
+    fn get_trade_state_parties_test() {
+        let wallet = Pubkey::new_unique();
+        let token_account_owner = Pubkey::new_unique();
+
+        // Listing
+        assert_eq!(
+            get_trade_state_parties(wallet, wallet),
+            (wallet, Pubkey::default())
+        );
+        // Bid or offer
+        assert_eq!(
+            get_trade_state_parties(wallet, token_account_owner),
+            (token_account_owner, wallet)
+        );
+    }
+
+    #[test]
+    fn parse_legacy_trade_state_test() {
+        assert_eq!(
+            parse_legacy_trade_state(&[254]).unwrap(),
+            (254, TradeStateSaleType::Auction)
+        );
+        assert_eq!(
+            parse_legacy_trade_state(&[254, 2, 0, 0]).unwrap(),
+            (254, TradeStateSaleType::InstantSale)
+        );
+        assert_eq!(
+            parse_legacy_trade_state(&[254, 3]).unwrap(),
+            (254, TradeStateSaleType::Offer)
+        );
+        // Unknown sale types were handled as auctions.
+        assert_eq!(
+            parse_legacy_trade_state(&[254, 0]).unwrap(),
+            (254, TradeStateSaleType::Auction)
+        );
+        assert_eq!(
+            parse_legacy_trade_state(&[254, 9]).unwrap(),
+            (254, TradeStateSaleType::Auction)
+        );
+
+        // Empty or executed trade states.
+        assert!(parse_legacy_trade_state(&[]).is_err());
+        assert!(parse_legacy_trade_state(&[0, 2]).is_err());
+
+        // Already migrated.
+        let mut data = <TradeState as anchor_lang::Discriminator>::discriminator().to_vec();
+        data.push(254);
+        assert!(parse_legacy_trade_state(&data).is_err());
+    }
+
+    #[test]
+    fn get_shared_escrow_available_balance_test() {
+        // Nothing reserved.
+        assert_eq!(get_shared_escrow_available_balance(110, 10, 0), 100);
+
+        // Partially and fully reserved.
+        assert_eq!(get_shared_escrow_available_balance(110, 10, 40), 60);
+        assert_eq!(get_shared_escrow_available_balance(110, 10, 100), 0);
+
+        // Never underflows.
+        assert_eq!(get_shared_escrow_available_balance(5, 10, 0), 0);
+        assert_eq!(get_shared_escrow_available_balance(110, 10, 200), 0);
+    }
+
+    #[test]
+    fn release_shared_escrow_amount_test() {
+        let mut shared_escrow = SharedEscrow {
+            reserved_amount: 100,
+            ..Default::default()
+        };
+
+        assert!(release_shared_escrow_amount(&mut shared_escrow, 40).is_ok());
+        assert_eq!(shared_escrow.reserved_amount, 60);
+
+        // Cannot release more than is reserved.
+        assert!(release_shared_escrow_amount(&mut shared_escrow, 61).is_err());
+        assert_eq!(shared_escrow.reserved_amount, 60);
+    }
+
+    #[test]
+    fn get_active_buy_now_price_test() {
+        assert_eq!(get_active_buy_now_price(None, 0), None);
+
+        // No bids, or bids below the buy now price.
+        assert_eq!(get_active_buy_now_price(Some(100), 0), Some(100));
+        assert_eq!(get_active_buy_now_price(Some(100), 99), Some(100));
+
+        // Bids have reached the buy now price.
+        assert_eq!(get_active_buy_now_price(Some(100), 100), None);
+        assert_eq!(get_active_buy_now_price(Some(100), 101), None);
+    }
+
+    #[test]
+    fn get_buy_now_purchase_price_test() {
+        let bidder = Some(Pubkey::new_unique());
+        assert_eq!(
+            get_buy_now_purchase_price(&get_auction_last_bid_price(0, None, 0, None)),
+            None
+        );
+        assert_eq!(
+            get_buy_now_purchase_price(&get_auction_last_bid_price(0, None, 0, Some(100))),
+            Some(100)
+        );
+        assert_eq!(
+            get_buy_now_purchase_price(&get_auction_last_bid_price(99, bidder, 0, Some(100))),
+            Some(100)
+        );
+        assert_eq!(
+            get_buy_now_purchase_price(&get_auction_last_bid_price(100, bidder, 0, Some(100))),
+            None
+        );
+
+        // A proxy bid whose max bid reaches the buy now price.
+        assert_eq!(
+            get_buy_now_purchase_price(&get_auction_last_bid_price(50, bidder, 99, Some(100))),
+            Some(100)
+        );
+        assert_eq!(
+            get_buy_now_purchase_price(&get_auction_last_bid_price(50, bidder, 100, Some(100))),
+            None
+        );
+    }
+
+    #[test]
+    fn get_creator_fee_amounts_test() {
+        let creators = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        // 10 basis points split evenly between the creators.
+        let metadata = get_metadata(Some(creators.clone()), false);
+
+        let (creator_fees, seller_amount) = get_creator_fee_amounts(&metadata, 10_000).unwrap();
+        assert_eq!(creator_fees, vec![(creators[0], 5), (creators[1], 5)]);
+        assert_eq!(seller_amount, 9_990);
+
+        // Dust goes to the seller.
+        let (creator_fees, seller_amount) = get_creator_fee_amounts(&metadata, 1_001).unwrap();
+        assert_eq!(creator_fees, vec![(creators[0], 0), (creators[1], 0)]);
+        assert_eq!(seller_amount, 1_001);
+
+        let (creator_fees, seller_amount) =
+            get_creator_fee_amounts(&get_metadata(None, false), 10_000).unwrap();
+        assert!(creator_fees.is_empty());
+        assert_eq!(seller_amount, 10_000);
+    }
+
+    fn get_revenue_split_recipient(basis_points: u16) -> RevenueSplitRecipient {
+        RevenueSplitRecipient {
+            address: Pubkey::new_unique(),
+            basis_points,
+        }
+    }
+
+    #[test]
+    fn assert_valid_revenue_split_recipients_test() {
+        assert!(assert_valid_revenue_split_recipients(&[]).is_ok());
+        assert!(
+            assert_valid_revenue_split_recipients(&[get_revenue_split_recipient(10000)]).is_ok()
+        );
+        assert!(assert_valid_revenue_split_recipients(&[
+            get_revenue_split_recipient(2500),
+            get_revenue_split_recipient(7500),
+        ])
+        .is_ok());
+
+        // Basis points must add up to 10,000.
+        assert!(
+            assert_valid_revenue_split_recipients(&[get_revenue_split_recipient(9999)]).is_err()
+        );
+        assert!(assert_valid_revenue_split_recipients(&[
+            get_revenue_split_recipient(10000),
+            get_revenue_split_recipient(0),
+        ])
+        .is_err());
+        assert!(assert_valid_revenue_split_recipients(&[
+            get_revenue_split_recipient(u16::MAX),
+            get_revenue_split_recipient(10000),
+        ])
+        .is_err());
+
+        let too_many_recipients =
+            vec![get_revenue_split_recipient(1000); MAX_REVENUE_SPLIT_RECIPIENTS + 1];
+        assert!(assert_valid_revenue_split_recipients(&too_many_recipients).is_err());
+    }
+
+    #[test]
+    fn get_revenue_split_amounts_test() {
+        let recipients = [
+            get_revenue_split_recipient(3333),
+            get_revenue_split_recipient(3333),
+            get_revenue_split_recipient(3334),
+        ];
+
+        // The last recipient gets the leftover.
+        assert_eq!(
+            get_revenue_split_amounts(&recipients, 100).unwrap(),
+            vec![
+                (recipients[0].address, 33),
+                (recipients[1].address, 33),
+                (recipients[2].address, 34),
+            ]
+        );
+        assert_eq!(
+            get_revenue_split_amounts(&recipients, 1_000_000).unwrap(),
+            vec![
+                (recipients[0].address, 333_300),
+                (recipients[1].address, 333_300),
+                (recipients[2].address, 333_400),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_primary_sale_amounts_test() {
+        let creators = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let metadata = get_metadata(Some(creators.clone()), false);
+
+        // The last creator gets the leftover.
+        assert_eq!(
+            get_primary_sale_amounts(&metadata, 101).unwrap(),
+            vec![(creators[0], 50), (creators[1], 51)]
+        );
+        assert!(get_primary_sale_amounts(&get_metadata(None, false), 101)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn assert_revenue_split_signed_by_creators_test() {
+        let creators = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let metadata = get_metadata(Some(creators.clone()), false);
+
+        assert!(assert_revenue_split_signed_by_creators(&metadata, &creators).is_ok());
+        assert!(assert_revenue_split_signed_by_creators(&metadata, &creators[..1]).is_err());
+
+        // A single creator is paid as the seller, so only the owner needs to sign.
+        let metadata = get_metadata(Some(vec![Pubkey::new_unique()]), false);
+        assert!(assert_revenue_split_signed_by_creators(&metadata, &[]).is_ok());
+        assert!(assert_revenue_split_signed_by_creators(&get_metadata(None, false), &[]).is_ok());
+
+        // Unverified creators can't sign for their share.
+        let mut metadata = get_metadata(Some(creators.clone()), false);
+        metadata.data.creators.as_mut().unwrap()[1].verified = false;
+        assert!(assert_revenue_split_signed_by_creators(&metadata, &creators[..1]).is_ok());
+    }
+
+    fn get_auction_last_bid_price(
+        price: u64,
+        bidder: Option<Pubkey>,
+        proxy_max_bid: u64,
+        buy_now_price: Option<u64>,
+    ) -> LastBidPrice {
+        LastBidPrice {
+            price,
+            bidder,
+            has_been_sold: 0,
+            tick_size_constant_in_lamports: 0,
+            has_campaign_escrow_treasury: false,
+            rent_payer: None,
+            version: 1,
+            bidder_uses_shared_escrow: false,
+            buy_now_price,
+            proxy_max_bid,
+            tick_size_percent: 0,
+            tick_size_min_in_lamports: 0,
+            tick_size_max_in_lamports: 0,
+            has_revenue_split: false,
+            buyer_premium_basis_points: 0,
+            has_audit_log: false,
+        }
+    }
+
+    fn get_outcome(is_highest_bid: bool, price: u64) -> AuctionBidOutcome {
+        AuctionBidOutcome {
+            is_highest_bid,
+            price,
+        }
+    }
+
+    #[test]
+    fn get_escrowed_bid_amount_test() {
+        let bidder = Some(Pubkey::new_unique());
+        assert_eq!(
+            get_escrowed_bid_amount(&get_auction_last_bid_price(100, bidder, 0, None)),
+            100
+        );
+        assert_eq!(
+            get_escrowed_bid_amount(&get_auction_last_bid_price(100, bidder, 500, None)),
+            500
+        );
+
+        let mut last_bid_price = get_auction_last_bid_price(1000, bidder, 0, None);
+        last_bid_price.buyer_premium_basis_points = 1500;
+        assert_eq!(get_max_bid(&last_bid_price), 1000);
+        assert_eq!(get_escrowed_bid_amount(&last_bid_price), 1150);
+        last_bid_price.proxy_max_bid = 2000;
+        assert_eq!(get_max_bid(&last_bid_price), 2000);
+        assert_eq!(get_escrowed_bid_amount(&last_bid_price), 2300);
+    }
+
+    #[test]
+    fn get_buyer_premium_test() {
+        assert_eq!(get_buyer_premium(1000, 0), 0);
+        assert_eq!(get_buyer_premium(1000, 250), 25);
+        assert_eq!(get_buyer_premium(1000, 10000), 1000);
+        // Rounds down.
+        assert_eq!(get_buyer_premium(999, 250), 24);
+        assert_eq!(get_buyer_premium(u64::MAX, 10000), u64::MAX);
+    }
+
+    fn get_fee_override(expiry: Option<i64>) -> FeeOverride {
+        FeeOverride {
+            auction_house: Pubkey::new_unique(),
+            key: Pubkey::new_unique(),
+            seller_fee_basis_points: 500,
+            seller_fee_basis_points_secondary: 100,
+            expiry,
+            bump: 0,
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn is_fee_override_active_test() {
+        assert!(is_fee_override_active(&get_fee_override(None), 1000));
+        assert!(is_fee_override_active(&get_fee_override(Some(1001)), 1000));
+        assert!(!is_fee_override_active(&get_fee_override(Some(1000)), 1000));
+        assert!(!is_fee_override_active(&get_fee_override(Some(999)), 1000));
+    }
+
+    #[test]
+    fn get_last_bid_price_snapshot_test() {
+        let bidder = Pubkey::new_unique();
+        let mut last_bid_price = get_auction_last_bid_price(100, Some(bidder), 500, None);
+        last_bid_price.has_been_sold = 1;
+
+        assert_eq!(
+            get_last_bid_price_snapshot(&last_bid_price),
+            LastBidPriceSnapshot {
+                price: 100,
+                bidder,
+                has_been_sold: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn get_last_bid_price_audit_log_size_test() {
+        assert_eq!(
+            get_last_bid_price_audit_log_size(0),
+            LAST_BID_PRICE_AUDIT_LOG_SIZE
+        );
+        assert_eq!(
+            get_last_bid_price_audit_log_size(2),
+            LAST_BID_PRICE_AUDIT_LOG_SIZE + 2 * LAST_BID_PRICE_OVERRIDE_SIZE
+        );
+    }
+
+    #[test]
+    fn has_auction_house_role_test() {
+        let listing_moderator = Pubkey::new_unique();
+        let auction_house_roles = AuctionHouseRoles {
+            listing_moderator,
+            ..Default::default()
+        };
+
+        assert!(has_auction_house_role(
+            &auction_house_roles,
+            AuctionHouseRole::ListingModerator,
+            &listing_moderator
+        ));
+        assert!(!has_auction_house_role(
+            &auction_house_roles,
+            AuctionHouseRole::DropManager,
+            &listing_moderator
+        ));
+        assert!(!has_auction_house_role(
+            &auction_house_roles,
+            AuctionHouseRole::FeeManager,
+            &ZERO_PUBKEY
+        ));
+    }
+
+    #[test]
+    fn get_pending_key_test() {
+        let current = Pubkey::new_unique();
+        let new = Pubkey::new_unique();
+        assert_eq!(get_pending_key(&current, &current), None);
+        assert_eq!(get_pending_key(&current, &new), Some(new));
+    }
+
+    #[test]
+    fn assert_pending_fees_effective_test() {
+        assert!(assert_pending_fees_effective(1000, 999).is_err());
+        assert!(assert_pending_fees_effective(1000, 1000).is_ok());
+        assert!(assert_pending_fees_effective(1000, 1001).is_ok());
+    }
+
+    #[test]
+    fn get_seller_fee_basis_points_test() {
+        let auction_house = AuctionHouse {
+            auction_house_fee_account: Pubkey::default(),
+            auction_house_treasury: Pubkey::default(),
+            treasury_withdrawal_destination: Pubkey::default(),
+            fee_withdrawal_destination: Pubkey::default(),
+            treasury_mint: Pubkey::default(),
+            authority: Pubkey::default(),
+            creator: Pubkey::default(),
+            bump: 0,
+            treasury_bump: 0,
+            fee_payer_bump: 0,
+            seller_fee_basis_points: 1500,
+            requires_sign_off: false,
+            can_change_sale_price: false,
+            seller_fee_basis_points_secondary: 250,
+            pay_all_fees: false,
+            version: 1,
+            default_tick_size: TickSize::default(),
+            claimable_balances_enabled: false,
+            buyer_premium_basis_points: 0,
+            pending_authority: None,
+            pending_treasury_withdrawal_destination: None,
+            pending_fee_withdrawal_destination: None,
+            pending_seller_fee_basis_points: None,
+            pending_seller_fee_basis_points_secondary: None,
+            pending_fees_effective_time: 0,
+            paused: false,
+            guardian: Pubkey::default(),
+            has_treasury_split: false,
+        };
+        let fee_override = get_fee_override(None);
+
+        assert_eq!(get_seller_fee_basis_points(&auction_house, None, false), 1500);
+        assert_eq!(get_seller_fee_basis_points(&auction_house, None, true), 250);
+        assert_eq!(
+            get_seller_fee_basis_points(&auction_house, Some(&fee_override), false),
+            500
+        );
+        assert_eq!(
+            get_seller_fee_basis_points(&auction_house, Some(&fee_override), true),
+            100
+        );
+
+        // Overrides can't raise fees.
+        let fee_override = FeeOverride {
+            seller_fee_basis_points: 2000,
+            seller_fee_basis_points_secondary: 300,
+            ..get_fee_override(None)
+        };
+        assert_eq!(
+            get_seller_fee_basis_points(&auction_house, Some(&fee_override), false),
+            1500
+        );
+        assert_eq!(
+            get_seller_fee_basis_points(&auction_house, Some(&fee_override), true),
+            250
+        );
+    }
+
+    #[test]
+    fn resolve_auction_bid_test() {
+        let bidder = Pubkey::new_unique();
+        let other_bidder = Pubkey::new_unique();
+        let tick_size = TickSize {
+            constant_in_lamports: 10,
+            ..TickSize::default()
+        };
+        let no_bids = get_auction_last_bid_price(0, Some(ZERO_PUBKEY), 0, None);
+
+        // First bids.
+        assert_eq!(
+            resolve_auction_bid(&no_bids, &bidder, 100, 100, &tick_size, 9).unwrap(),
+            get_outcome(true, 100)
+        );
+        assert_eq!(
+            resolve_auction_bid(&no_bids, &bidder, 100, 500, &tick_size, 9).unwrap(),
+            get_outcome(true, 100)
+        );
+        assert!(resolve_auction_bid(&no_bids, &bidder, 501, 500, &tick_size, 9).is_err());
+
+        // Regular bid against a regular bid.
+        let regular_bid = get_auction_last_bid_price(100, Some(other_bidder), 0, None);
+        assert_eq!(
+            resolve_auction_bid(&regular_bid, &bidder, 110, 110, &tick_size, 9).unwrap(),
+            get_outcome(true, 110)
+        );
+        assert!(resolve_auction_bid(&regular_bid, &bidder, 109, 109, &tick_size, 9).is_err());
+
+        // Proxy bid against a regular bid only goes one tick above it.
+        assert_eq!(
+            resolve_auction_bid(&regular_bid, &bidder, 0, 500, &tick_size, 9).unwrap(),
+            get_outcome(true, 110)
+        );
+        assert_eq!(
+            resolve_auction_bid(&regular_bid, &bidder, 200, 500, &tick_size, 9).unwrap(),
+            get_outcome(true, 200)
+        );
+
+        // Standing proxy bid counters lower or equal max bids.
+        let proxy_bid = get_auction_last_bid_price(100, Some(other_bidder), 500, None);
+        assert_eq!(
+            resolve_auction_bid(&proxy_bid, &bidder, 200, 200, &tick_size, 9).unwrap(),
+            get_outcome(false, 210)
+        );
+        assert_eq!(
+            resolve_auction_bid(&proxy_bid, &bidder, 495, 495, &tick_size, 9).unwrap(),
+            get_outcome(false, 500)
+        );
+        assert_eq!(
+            resolve_auction_bid(&proxy_bid, &bidder, 110, 500, &tick_size, 9).unwrap(),
+            get_outcome(false, 500)
+        );
+
+        // Higher max bids beat the standing proxy bid.
+        assert_eq!(
+            resolve_auction_bid(&proxy_bid, &bidder, 600, 600, &tick_size, 9).unwrap(),
+            get_outcome(true, 600)
+        );
+        assert_eq!(
+            resolve_auction_bid(&proxy_bid, &bidder, 0, 1000, &tick_size, 9).unwrap(),
+            get_outcome(true, 510)
+        );
+        assert_eq!(
+            resolve_auction_bid(&proxy_bid, &bidder, 0, 505, &tick_size, 9).unwrap(),
+            get_outcome(true, 505)
+        );
+
+        // The current bidder raising their max bid keeps the visible price.
+        let own_proxy_bid = get_auction_last_bid_price(100, Some(bidder), 500, None);
+        assert_eq!(
+            resolve_auction_bid(&own_proxy_bid, &bidder, 0, 1000, &tick_size, 9).unwrap(),
+            get_outcome(true, 100)
+        );
+        assert!(resolve_auction_bid(&own_proxy_bid, &bidder, 0, 105, &tick_size, 9).is_err());
+
+        // Bids at or above the buy now price ignore the tick size.
+        let buy_now = get_auction_last_bid_price(100, Some(other_bidder), 0, Some(105));
+        assert_eq!(
+            resolve_auction_bid(&buy_now, &bidder, 105, 105, &tick_size, 9).unwrap(),
+            get_outcome(true, 105)
+        );
+        assert_eq!(
+            resolve_auction_bid(&buy_now, &bidder, 0, 500, &tick_size, 9).unwrap(),
+            get_outcome(true, 105)
+        );
+    }
+
+    #[test]
+    fn get_sealed_bid_commitment_test() {
+        let salt = [7; 32];
+        let bidder = Pubkey::new_unique();
+        let commitment = get_sealed_bid_commitment(100, &salt, &bidder);
+
+        assert_eq!(commitment, get_sealed_bid_commitment(100, &salt, &bidder));
+        assert_ne!(commitment, get_sealed_bid_commitment(101, &salt, &bidder));
+        assert_ne!(commitment, get_sealed_bid_commitment(100, &[8; 32], &bidder));
+        assert_ne!(
+            commitment,
+            get_sealed_bid_commitment(100, &salt, &Pubkey::new_unique())
+        );
+    }
+
+    #[test]
+    fn sealed_bid_auction_settlement_test() {
+        let first_bidder = Pubkey::new_unique();
+        let second_bidder = Pubkey::new_unique();
+        let third_bidder = Pubkey::new_unique();
+        let mut sealed_bid_auction = SealedBidAuction {
+            reserve_price: 50,
+            reveal_end_time: 100,
+            ..Default::default()
+        };
+
+        record_sealed_bid_reveal(&mut sealed_bid_auction, &first_bidder, 80);
+        assert_eq!(sealed_bid_auction.highest_bidder, Some(first_bidder));
+        assert_eq!(sealed_bid_auction.highest_bid, 80);
+        assert_eq!(sealed_bid_auction.second_highest_bid, 0);
+
+        record_sealed_bid_reveal(&mut sealed_bid_auction, &second_bidder, 120);
+        record_sealed_bid_reveal(&mut sealed_bid_auction, &third_bidder, 90);
+        assert_eq!(sealed_bid_auction.highest_bidder, Some(second_bidder));
+        assert_eq!(sealed_bid_auction.highest_bid, 120);
+        assert_eq!(sealed_bid_auction.second_highest_bid, 90);
+
+        // Ties go to the bid which was revealed first.
+        record_sealed_bid_reveal(&mut sealed_bid_auction, &third_bidder, 120);
+        assert_eq!(sealed_bid_auction.highest_bidder, Some(second_bidder));
+        assert_eq!(sealed_bid_auction.second_highest_bid, 120);
+
+        // First price.
+        sealed_bid_auction.second_highest_bid = 90;
+        assert_eq!(get_sealed_bid_clearing_price(&sealed_bid_auction), 120);
+        assert!(assert_sealed_bid_auction_winner(&sealed_bid_auction, &second_bidder, 120, 100)
+            .is_ok());
+        assert!(assert_sealed_bid_auction_winner(&sealed_bid_auction, &second_bidder, 90, 100)
+            .is_err());
+        assert!(assert_sealed_bid_auction_winner(&sealed_bid_auction, &third_bidder, 120, 100)
+            .is_err());
+        // Reveal phase has not ended.
+        assert!(assert_sealed_bid_auction_winner(&sealed_bid_auction, &second_bidder, 120, 99)
+            .is_err());
+
+        // Second price, with the reserve price as the floor.
+        sealed_bid_auction.is_second_price = true;
+        assert_eq!(get_sealed_bid_clearing_price(&sealed_bid_auction), 90);
+        sealed_bid_auction.second_highest_bid = 0;
+        assert_eq!(get_sealed_bid_clearing_price(&sealed_bid_auction), 50);
+
+        sealed_bid_auction.is_settled = true;
+        assert!(assert_sealed_bid_auction_winner(&sealed_bid_auction, &second_bidder, 50, 100)
+            .is_err());
+    }
+
+    #[test]
+    fn shift_legacy_edition_allowlist_settings_test() {
+        let merkle_roots = vec![[1; 32], [2; 32]];
+        let mut data = vec![0; EDITION_ALLOWLIST_SETTINGS_SIZE];
+        data[..8].copy_from_slice(
+            &<EditionAllowlistSettings as anchor_lang::Discriminator>::discriminator(),
+        );
+        data[8] = 255;
+        let serialized_roots = merkle_roots.try_to_vec().unwrap();
+        data[9..9 + serialized_roots.len()].copy_from_slice(&serialized_roots);
+
+        shift_legacy_edition_allowlist_settings(&mut data);
+
+        let edition_allowlist_settings =
+            EditionAllowlistSettings::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(edition_allowlist_settings.bump, 255);
+        assert_eq!(edition_allowlist_settings.version, 0);
+        assert_eq!(edition_allowlist_settings.merkle_roots, merkle_roots);
+    }
 }
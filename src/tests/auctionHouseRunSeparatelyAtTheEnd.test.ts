@@ -13,6 +13,10 @@ import {
   FEE_WITHDRAWAL_DESTINATION,
   ZERO_PUBKEY,
 } from "tests/constants/AuctionHouse";
+import {
+  TRADE_STATE_BUMP_OFFSET,
+  TRADE_STATE_SALE_TYPE_OFFSET,
+} from "tests/constants/TradeState";
 import { WALLET_CREATOR } from "tests/constants/Wallets";
 import expectTransactionToFailWithErrorCode from "tests/utils/errors/expectTransactionToFailWithErrorCode";
 import expectEqPubkeys from "tests/utils/expectEqPubkeys";
@@ -156,8 +160,12 @@ describe("misc tests (create, update, deposit, withdraw)", () => {
       BUY_PRICE * LAMPORTS_PER_SOL
     );
     const tradeStateAccount = await connection.getAccountInfo(tradeState);
-    expect(Number(tradeStateAccount!.data[0])).toEqual(tradeStateBump);
-    expect(tradeStateAccount!.data[1]).toEqual(SaleType.InstantSale);
+    expect(Number(tradeStateAccount!.data[TRADE_STATE_BUMP_OFFSET])).toEqual(
+      tradeStateBump
+    );
+    expect(tradeStateAccount!.data[TRADE_STATE_SALE_TYPE_OFFSET]).toEqual(
+      SaleType.InstantSale
+    );
   });
 
   it("set previous bidder", async () => {
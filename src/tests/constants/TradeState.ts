@@ -0,0 +1,5 @@
+// Layout of TradeState, see programs/formfn-auction-house/src/state/trade_state.rs.
+// The fields come after the 8 byte discriminator.
+export const TRADE_STATE_SIZE = 171;
+export const TRADE_STATE_BUMP_OFFSET = 8;
+export const TRADE_STATE_SALE_TYPE_OFFSET = 10;
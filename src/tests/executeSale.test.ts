@@ -18,6 +18,7 @@ import {
   BUY_PRICE,
   ZERO_PUBKEY,
 } from "tests/constants/AuctionHouse";
+import { TRADE_STATE_SALE_TYPE_OFFSET } from "tests/constants/TradeState";
 import { WALLET_CREATOR } from "tests/constants/Wallets";
 import { IS_NATIVE } from "tests/setup";
 import buy from "tests/utils/buy";
@@ -527,7 +528,9 @@ describe("execute sale tests", () => {
       );
       if (buyPriceInSol === listingPriceInSol) {
         expect(tradeStateAccount).not.toBe(null);
-        expect(tradeStateAccount!.data[1]).toEqual(saleType);
+        expect(tradeStateAccount!.data[TRADE_STATE_SALE_TYPE_OFFSET]).toEqual(
+          saleType
+        );
       } else {
         expect(tradeStateAccount).toBe(null);
       }
@@ -67,8 +67,7 @@ async function createTradeState(
   await verifyTradeState(auctionHouseSdk, connection, {
     expectNull: false,
     priceInSol,
-    saleType: allocationSize === 1 ? undefined : saleType,
-    size: allocationSize,
+    saleType,
     tokenAccount,
     tokenMint,
     wallet,
@@ -147,7 +146,7 @@ describe("trade state tests", () => {
     await requestAirdrops({ connection, wallets: [buyer, seller, seller2] });
   });
 
-  it("verify creating trade state with no allocation size creates it with 130 bytes", async () => {
+  it("verify creating trade state creates a TradeState account", async () => {
     await createTradeState(BUY_PRICE, SaleType.InstantSale, seller.publicKey);
 
     await resetTradeState(auctionHouseSdk, connection, {
@@ -172,8 +171,8 @@ describe("trade state tests", () => {
     });
   });
 
-  it("verify creating trade state with allocation size creates it with specified size", async () => {
-    // Allocate with 1 byte
+  it("verify creating trade state ignores the allocation size", async () => {
+    // Allocation sizes are no longer used, trade states are always TradeState accounts
     const allocationSize = 1;
     await createTradeState(
       BUY_PRICE,
@@ -183,7 +182,6 @@ describe("trade state tests", () => {
     );
 
     // Check that re-calling `createTradeState` without cancelling does nothing
-    // by ensuring allocation size is the same as before
     const tx = await auctionHouseSdk.createTradeStateTx({
       allocationSize: allocationSize + 1,
       priceInLamports: BUY_PRICE * LAMPORTS_PER_SOL,
@@ -200,11 +198,10 @@ describe("trade state tests", () => {
     });
 
     // Check that re-calling `createTradeState` without cancelling does nothing
-    // by ensuring allocation size is the same as before
     await verifyTradeState(auctionHouseSdk, connection, {
       expectNull: false,
       priceInSol: BUY_PRICE,
-      size: allocationSize,
+      saleType: SaleType.InstantSale,
       tokenAccount,
       tokenMint,
       wallet: seller.publicKey,
@@ -332,8 +329,8 @@ describe("trade state tests", () => {
   });
 
   it("can cancel and re-create trade state on existing listings with trade states", async () => {
-    // Manually create trade state with size 1 byte and have seller list
-    // without calling create trade state (call sell Ix directly to simulate old listings)
+    // Create trade state with size 1 byte (which used to create a legacy 1 byte trade state)
+    // and have seller list by calling the sell Ix directly
     await createTradeState(BUY_PRICE, SaleType.Auction, seller.publicKey, 1);
     const sellIx = await auctionHouseSellIx(
       {
@@ -352,7 +349,7 @@ describe("trade state tests", () => {
     );
     await sendTransactionWithWallet(connection, ixToTx(sellIx), seller);
 
-    // Trade state should exist but sale type will not be set
+    // Trade state should exist as an auction
     await verifyTradeState(auctionHouseSdk, connection, {
       expectNull: false,
       priceInSol: BUY_PRICE,
@@ -497,7 +494,7 @@ describe("trade state tests", () => {
       {}
     );
     await sendTransactionWithWallet(connection, ixToTx(sellIx), seller);
-    // Trade state should exist but sale type will not be set
+    // Trade state should exist as an auction
     await verifyTradeState(auctionHouseSdk, connection, {
       expectNull: false,
       priceInSol: BUY_PRICE,
@@ -527,7 +524,7 @@ describe("trade state tests", () => {
       {}
     );
     await sendTransactionWithWallet(connection, ixToTx(buyIx), buyer);
-    // Trade state should exist but sale type will not be set
+    // Trade state should exist as an auction
     await verifyTradeState(auctionHouseSdk, connection, {
       expectNull: false,
       priceInSol: BUY_PRICE,
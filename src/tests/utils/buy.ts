@@ -7,6 +7,7 @@ import {
 } from "@solana/web3.js";
 import { Dayjs } from "dayjs";
 import AuctionHouseSdk from "solana/auction-house/AuctionHouseSdk";
+import { TRADE_STATE_BUMP_OFFSET } from "tests/constants/TradeState";
 import { WALLET_CREATOR } from "tests/constants/Wallets";
 import getBuyerEscrowLamports from "tests/utils/getBuyerEscrowLamports";
 import sendTransactionWithWallet from "tests/utils/txs/sendTransactionWithWallet";
@@ -100,5 +101,7 @@ export default async function buy(
   );
   const buyerTradeState = await connection.getAccountInfo(tradeState);
   expect(buyerTradeState).toBeDefined();
-  expect(Number(buyerTradeState!.data[0])).toEqual(tradeBump);
+  expect(Number(buyerTradeState!.data[TRADE_STATE_BUMP_OFFSET])).toEqual(
+    tradeBump
+  );
 }
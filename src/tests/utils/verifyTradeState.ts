@@ -1,10 +1,12 @@
 import { Connection, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import AuctionHouseSdk from "solana/auction-house/AuctionHouseSdk";
+import {
+  TRADE_STATE_BUMP_OFFSET,
+  TRADE_STATE_SALE_TYPE_OFFSET,
+  TRADE_STATE_SIZE,
+} from "tests/constants/TradeState";
 import SaleType from "types/enum/SaleType";
 
-// See programs/formfn-auction-house/lib.rs
-const DEFAULT_TRADE_STATE_SIZE = 130;
-
 export default async function verifyTradeState(
   sdk: AuctionHouseSdk,
   connection: Connection,
@@ -15,12 +17,10 @@ export default async function verifyTradeState(
     expectNull,
     priceInSol,
     saleType,
-    size,
   }: {
     expectNull: boolean;
     priceInSol: number;
     saleType?: SaleType;
-    size?: number;
     tokenAccount: PublicKey;
     tokenMint: PublicKey;
     wallet: PublicKey;
@@ -37,14 +37,13 @@ export default async function verifyTradeState(
   if (expectNull) {
     expect(tradeStateAccount).toBe(null);
   } else {
-    expect(tradeStateAccount!.data[0]).toEqual(tradeStateBump);
-    if (size != null) {
-      expect(tradeStateAccount!.data.byteLength).toEqual(
-        size ?? DEFAULT_TRADE_STATE_SIZE
-      );
-      if (size > 1) {
-        expect(tradeStateAccount!.data[1]).toEqual(saleType ?? 0);
-      }
-    }
+    expect(tradeStateAccount!.data.byteLength).toEqual(TRADE_STATE_SIZE);
+    expect(tradeStateAccount!.data[TRADE_STATE_BUMP_OFFSET]).toEqual(
+      tradeStateBump
+    );
+    // sell and buy create trade states which don't exist yet as auctions
+    expect(tradeStateAccount!.data[TRADE_STATE_SALE_TYPE_OFFSET]).toEqual(
+      saleType ?? SaleType.Auction
+    );
   }
 }
@@ -3,6 +3,7 @@ enum SaleType {
   Auction = 1,
   InstantSale = 2,
   Offer = 3,
+  SealedBidAuction = 4,
 }
 
 export default SaleType;